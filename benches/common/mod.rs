@@ -0,0 +1,63 @@
+//! Allocation counting shared by benchmarks, used to show the effect of
+//! pre-sizing buffers which timing alone doesn't isolate.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static REALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Number of heap allocations and reallocations made by a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allocations {
+    pub allocations: usize,
+    pub reallocations: usize,
+}
+
+/// Calls `f` and returns the number of allocations it made.
+///
+/// Benchmarks run on a single thread, so other threads don't affect counts.
+pub fn count_allocations<T>(f: impl FnOnce() -> T) -> Allocations {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let reallocations = REALLOCATIONS.load(Ordering::Relaxed);
+    let result = f();
+    let counts = Allocations {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        reallocations: REALLOCATIONS.load(Ordering::Relaxed) - reallocations,
+    };
+    drop(result);
+    counts
+}
+
+/// Prints allocations made by `name`d variants of a benchmark.
+pub fn report_allocations(group: &str, variants: &[(&str, Allocations)]) {
+    for (name, counts) in variants {
+        println!(
+            "{}/{}: {} allocations, {} reallocations",
+            group, name, counts.allocations, counts.reallocations
+        );
+    }
+}
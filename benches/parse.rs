@@ -0,0 +1,82 @@
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::{CommandData, PathData, PathSegment},
+};
+
+fn segments(count: usize) -> impl Iterator<Item = PathSegment> {
+    std::iter::once(PathSegment {
+        relative: false,
+        data: CommandData::Move([0.0, 0.0]),
+    })
+    .chain((1..count).map(|i| {
+        let x = i as f32;
+        PathSegment {
+            relative: i % 2 == 0,
+            data: CommandData::Cubic([x, x * 0.5, x + 1.0, x * 0.25, x + 2.0, -x]),
+        }
+    }))
+}
+
+fn growing_vector() -> PathData {
+    let mut path = PathData {
+        segments: Vec::new(),
+    };
+    for segment in segments(black_box(10_000)) {
+        path.push(segment);
+    }
+    path
+}
+
+fn build_path(c: &mut Criterion) {
+    let with_capacity = || {
+        let mut path = PathData::with_capacity(10_000);
+        for segment in segments(black_box(10_000)) {
+            path.push(segment);
+        }
+        path
+    };
+
+    let growing_counts = common::count_allocations(growing_vector);
+    let sized_counts = common::count_allocations(with_capacity);
+    common::report_allocations(
+        "build 10k segment path",
+        &[
+            ("growing vector", growing_counts),
+            ("with_capacity", sized_counts),
+        ],
+    );
+    assert_eq!(sized_counts.reallocations, 0);
+
+    let mut group = c.benchmark_group("build 10k segment path");
+    group.bench_function("growing vector", |b| b.iter(growing_vector));
+    group.bench_function("with_capacity", |b| b.iter(with_capacity));
+    group.finish();
+}
+
+fn parse_path(c: &mut Criterion) {
+    let mut path = PathData::with_capacity(10_000);
+    path.extend(segments(10_000));
+    let d = path.write_to_string(&WriteSettings::default());
+    let parse = || black_box(&d).parse::<PathData>().unwrap();
+
+    // parsing estimates capacity from command letters, so it shouldn't
+    // reallocate segments like a growing vector does
+    let growing_counts = common::count_allocations(growing_vector);
+    let parse_counts = common::count_allocations(parse);
+    common::report_allocations(
+        "parse 10k segment path",
+        &[
+            ("growing vector", growing_counts),
+            ("estimated capacity", parse_counts),
+        ],
+    );
+    assert!(parse_counts.reallocations < growing_counts.reallocations);
+
+    c.bench_function("parse 10k segment path", |b| b.iter(parse));
+}
+
+criterion_group!(benches, build_path, parse_path);
+criterion_main!(benches);
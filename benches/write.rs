@@ -0,0 +1,81 @@
+mod common;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::{CachedPath, CommandData, PathData, PathSegment},
+};
+
+fn long_path(segments: usize) -> PathData {
+    let mut path = PathData::with_capacity(segments);
+    path.push(PathSegment {
+        relative: false,
+        data: CommandData::Move([0.0, 0.0]),
+    });
+    for i in 1..segments {
+        let x = i as f32;
+        path.push(PathSegment {
+            relative: i % 2 == 0,
+            data: CommandData::Cubic([x, x * 0.5, x + 1.0, x * 0.25, x + 2.0, -x]),
+        });
+    }
+    path
+}
+
+fn write_path(c: &mut Criterion) {
+    let path = long_path(10_000);
+    let settings = WriteSettings::default();
+
+    let unsized_buffer = || {
+        let mut buffer = Vec::new();
+        black_box(&path).write_to(&mut buffer, &settings).unwrap();
+        buffer
+    };
+    let hinted_buffer = || black_box(&path).write_to_string(&settings);
+
+    let unsized_counts = common::count_allocations(unsized_buffer);
+    let hinted_counts = common::count_allocations(hinted_buffer);
+    common::report_allocations(
+        "write 10k segment path",
+        &[
+            ("unsized buffer", unsized_counts),
+            ("size_hint buffer", hinted_counts),
+        ],
+    );
+    assert!(hinted_counts.reallocations < unsized_counts.reallocations);
+
+    let mut group = c.benchmark_group("write 10k segment path");
+    group.bench_function("unsized buffer", |b| b.iter(unsized_buffer));
+    group.bench_function("size_hint buffer", |b| b.iter(hinted_buffer));
+    group.finish();
+}
+
+fn write_repeated_path(c: &mut Criterion) {
+    let path = long_path(100);
+    let cached = CachedPath::new(path.clone());
+    let settings = WriteSettings::default();
+
+    let mut group = c.benchmark_group("write 100 segment path 1000 times");
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            let mut buffer = Vec::new();
+            for _ in 0..1000 {
+                black_box(&path).write_to(&mut buffer, &settings).unwrap();
+            }
+            buffer
+        })
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            let mut buffer = Vec::new();
+            for _ in 0..1000 {
+                black_box(&cached).write_to(&mut buffer, &settings).unwrap();
+            }
+            buffer
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, write_path, write_repeated_path);
+criterion_main!(benches);
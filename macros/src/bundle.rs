@@ -1,3 +1,5 @@
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::parse::Parse;
 use syn::*;
 
@@ -10,7 +12,13 @@ impl KVPairs {
     /// `None` if attribute with `name` is not present, `Err` if KVPairs is
     /// invalid, otherwise `Some(Ok(kv_pairs))`.
     pub fn from_field_attribute(field: &Field, name: impl AsRef<str>) -> Option<Result<Self>> {
-        field.attrs.iter().find_map(|attr| match &attr.meta {
+        Self::from_attributes(&field.attrs, name)
+    }
+
+    /// Same as [`KVPairs::from_field_attribute`], but for any list of
+    /// attributes (e.g. ones on the derived struct itself).
+    pub fn from_attributes(attrs: &[Attribute], name: impl AsRef<str>) -> Option<Result<Self>> {
+        attrs.iter().find_map(|attr| match &attr.meta {
             Meta::Path(path) => {
                 if path.is_ident(name.as_ref()) {
                     Some(Ok(KVPairs::default()))
@@ -75,53 +83,110 @@ pub enum ValueExpression {
 }
 
 impl ValueExpression {
-    pub fn handle(&self, name: &Ident, attrib_name: &LitByteStr) -> Block {
-        let key = {
+    /// Statements writing the attribute value held by `name` into `writer`.
+    fn value(&self, name: &Ident) -> TokenStream {
+        match self {
+            ValueExpression::Pass => quote! {
+                // string-backed values are escaped here so their
+                // implementations don't have to
+                match crate::io::Writable::as_attribute_str( #name ) {
+                    Some(value) => std::io::Write::write_all(
+                        &mut crate::io::WriteEscaped::for_settings(writer, settings),
+                        value.as_bytes(),
+                    )?,
+                    None => crate::io::Writable::write_to( #name , writer, settings)?,
+                }
+            },
+            // transformed bytes are escaped as well, they can come from
+            // unvalidated strings
+            ValueExpression::Transform(expr) => quote! {
+                std::io::Write::write_all(
+                    &mut crate::io::WriteEscaped::for_settings(writer, settings),
+                    #expr,
+                )?;
+            },
+            ValueExpression::Literal(literal) => quote! {
+                writer.write_all( #literal )?;
+            },
+        }
+    }
+
+    pub fn handle(
+        &self,
+        name: &Ident,
+        attrib_name: &LitByteStr,
+        html_name: Option<&LitByteStr>,
+    ) -> Block {
+        let key = |attrib_name: &LitByteStr| {
             let mut name = attrib_name.value();
-            name.extend_from_slice(b"=\"");
+            name.push(b'=');
             LitByteStr::new(name.as_slice(), attrib_name.span())
         };
 
-        match self {
-            ValueExpression::Pass => {
-                parse_quote! {{
-                    if wrote_any_attributes {
-                        writer.write(b" ")?;
-                    }
-                    writer.write( #key )?;
-                    crate::io::Writable::write_to( #name , writer, settings)?;
-                    writer.write(b"\"")?;
-                    wrote_any_attributes = true;
-                }}
-            }
-            ValueExpression::Transform(expr) => {
-                parse_quote! {{
-                    if wrote_any_attributes {
-                        writer.write(b" ")?;
-                    }
-                    writer.write( #key )?;
-                    writer.write( #expr )?;
-                    writer.write(b"\"")?;
-                    wrote_any_attributes = true;
-                }}
+        Self::wrap(key(attrib_name), html_name.map(key), self.value(name))
+    }
+
+    /// Same as [`ValueExpression::handle`], but pushes the attribute name and
+    /// written value into `attribute_pairs` instead.
+    pub fn handle_pair(
+        &self,
+        name: &Ident,
+        attrib_name: &LitByteStr,
+        html_name: Option<&LitByteStr>,
+    ) -> Block {
+        let attrib_name: Expr = match html_name {
+            Some(html_name) => parse_quote! {
+                if settings.dialect == crate::io::Dialect::Html {
+                    #html_name
+                } else {
+                    #attrib_name
+                }
+            },
+            None => parse_quote! { #attrib_name },
+        };
+        let value = self.value(name);
+
+        parse_quote! {{
+            use std::io::Write as _;
+            // prefixed so they don't shadow bindings of fields
+            let __name: &'static [u8] = #attrib_name;
+            let mut __value = Vec::new();
+            {
+                let writer = &mut __value;
+                #value
             }
-            ValueExpression::Literal(literal) => {
-                let literal = {
-                    let mut value = key.value();
-                    value.extend_from_slice(literal.value().as_ref());
-                    value.extend_from_slice(b"\"");
-                    LitByteStr::new(value.as_slice(), literal.span())
-                };
-
-                parse_quote! {{
-                    if wrote_any_attributes {
-                        writer.write(b" ")?;
-                    }
-                    writer.write( #literal )?;
-                    wrote_any_attributes = true;
-                }}
+            attribute_pairs.push((std::borrow::Cow::Borrowed(__name), __value));
+        }}
+    }
+
+    fn wrap(
+        prefix: LitByteStr,
+        html_prefix: Option<LitByteStr>,
+        value: TokenStream,
+    ) -> Block {
+        let write_prefix: Stmt = match html_prefix {
+            Some(html_prefix) => parse_quote! {
+                if settings.dialect == crate::io::Dialect::Html {
+                    writer.write_all( #html_prefix )?;
+                } else {
+                    writer.write_all( #prefix )?;
+                }
+            },
+            None => parse_quote! {
+                writer.write_all( #prefix )?;
+            },
+        };
+
+        parse_quote! {{
+            if wrote_any_attributes {
+                writer.write_all(b" ")?;
             }
-        }
+            #write_prefix
+            settings.write_attribute_quote(writer)?;
+            #value
+            settings.write_attribute_quote(writer)?;
+            wrote_any_attributes = true;
+        }}
     }
 }
 
@@ -161,7 +226,7 @@ impl Check {
             }
             Check::Default => {
                 parse_quote! {
-                    if self. #name == Default::default() {
+                    if self. #name != Default::default() {
                         #inner
                     }
                 }
@@ -177,35 +242,90 @@ impl Check {
     }
 }
 
+/// Conversion applied to field identifiers to produce attribute names when
+/// they aren't explicitly specified with `name`.
+#[derive(Clone, Copy, Default)]
+pub enum RenameRule {
+    /// Field identifier is used as is.
+    #[default]
+    None,
+    /// `snake_case` field identifiers are converted to `kebab-case`.
+    KebabCase,
+}
+
+impl RenameRule {
+    /// Reads `rename_all` rule from `#[xml_attribute_bundle { .. }]` attribute
+    /// placed on the derived struct.
+    pub fn from_container(input: &DeriveInput) -> Result<Self> {
+        let pairs = match KVPairs::from_attributes(&input.attrs, "xml_attribute_bundle") {
+            Some(it) => it?,
+            None => return Ok(RenameRule::None),
+        };
+
+        match pairs.get("rename_all") {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(rule),
+                ..
+            })) => match rule.value().as_str() {
+                "kebab-case" => Ok(RenameRule::KebabCase),
+                _ => Err(Error::new_spanned(rule, "expected one of: 'kebab-case'")),
+            },
+            Some(other) => Err(Error::new_spanned(other, "expected a string literal")),
+            None => Ok(RenameRule::None),
+        }
+    }
+
+    pub fn apply(&self, name: &Ident) -> String {
+        let name = name.to_string();
+        let name = name.strip_prefix("r#").unwrap_or(&name);
+        match self {
+            RenameRule::None => name.to_string(),
+            RenameRule::KebabCase => name.replace('_', "-"),
+        }
+    }
+}
+
 pub struct XmlAttribute {
     pub name: Ident,
     pub attrib_name: LitByteStr,
-    pub ty: Type,
+    /// Name used instead of `attrib_name` when writing HTML.
+    pub html_name: Option<LitByteStr>,
     pub check: Check,
     pub value_expr: ValueExpression,
+    /// Type values are read as; inner type for `Option` fields.
+    pub value_ty: Type,
+    pub optional: bool,
 }
 
-fn is_option(ty: &Type) -> bool {
+/// Returns `T` if `ty` is `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
     match ty {
         Type::Path(path) => {
-            let last = match path.path.segments.last() {
-                Some(it) => it,
-                None => return false,
-            };
-
-            let one_arg = match &last.arguments {
-                PathArguments::AngleBracketed(args) => args.args.len() == 1,
-                _ => false,
-            };
+            let last = path.path.segments.last()?;
+            if last.ident != "Option" {
+                return None;
+            }
 
-            last.ident == "Option" && one_arg
+            match &last.arguments {
+                PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                    match args.args.first() {
+                        Some(GenericArgument::Type(inner)) => Some(inner),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
         }
-        _ => false,
+        _ => None,
     }
 }
 
+fn is_option(ty: &Type) -> bool {
+    option_inner(ty).is_some()
+}
+
 impl XmlAttribute {
-    pub fn new(field: &Field) -> Option<Result<Self>> {
+    pub fn new(field: &Field, rename: RenameRule) -> Option<Result<Self>> {
         let pairs = match KVPairs::from_field_attribute(field, "xml_attribute")? {
             Ok(it) => it,
             Err(err) => return Some(Err(err)),
@@ -287,20 +407,79 @@ impl XmlAttribute {
                 }
             }
         } else {
-            LitByteStr::new(name.to_string().as_bytes(), name.span())
+            LitByteStr::new(rename.apply(&name).as_bytes(), name.span())
         };
 
+        let html_name = match pairs.get("html_name") {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(name),
+                ..
+            })) => Some(LitByteStr::new(name.value().as_bytes(), name.span())),
+            Some(other) => {
+                return Some(Err(Error::new_spanned(other, "expected a string literal")));
+            }
+            None => None,
+        };
+
+        let optional = is_option(&ty);
+        let value_ty = option_inner(&ty).unwrap_or(&ty).clone();
+
         Some(Ok(XmlAttribute {
             name,
             attrib_name,
-            ty,
+            html_name,
             check,
             value_expr,
+            value_ty,
+            optional,
         }))
     }
 
-    pub fn generate_write_expr(self) -> Expr {
-        let inner = self.value_expr.handle(&self.name, &self.attrib_name);
+    /// Generates a match arm which reads the attribute value into the field.
+    pub fn generate_read_arm(&self) -> Arm {
+        let name = &self.name;
+        let value_ty = &self.value_ty;
+        let as_str =
+            |name: &LitByteStr| LitStr::new(&String::from_utf8_lossy(&name.value()), name.span());
+        let attrib_name = as_str(&self.attrib_name);
+        let pattern = match &self.html_name {
+            Some(html_name) => {
+                let html_name = as_str(html_name);
+                quote! { #attrib_name | #html_name }
+            }
+            None => quote! { #attrib_name },
+        };
+
+        let read = quote! {
+            <#value_ty as crate::io::Readable>::read(value).ok_or_else(|| {
+                crate::error::InvalidAttributeValue::new(name, value)
+            })?
+        };
+        let assign = if self.optional {
+            quote! { self. #name = Some(#read); }
+        } else {
+            quote! { self. #name = #read; }
+        };
+
+        parse_quote! {
+            #pattern => {
+                #assign
+                return Ok(true);
+            }
+        }
+    }
+
+    pub fn generate_write_expr(&self) -> Expr {
+        let inner = self
+            .value_expr
+            .handle(&self.name, &self.attrib_name, self.html_name.as_ref());
+        self.check.wrapped(&self.name, inner)
+    }
+
+    pub fn generate_pair_expr(&self) -> Expr {
+        let inner =
+            self.value_expr
+                .handle_pair(&self.name, &self.attrib_name, self.html_name.as_ref());
         self.check.wrapped(&self.name, inner)
     }
 }
@@ -326,11 +505,26 @@ impl XmlAttributeBundle {
 
     pub fn generate_write_expr(&self) -> Expr {
         let name = &self.name;
+        // separator is only written if the bundle writes any attributes
         parse_quote! {{
-            if wrote_any_attributes {
-                writer.write(b" ")?;
-            }
-            wrote_any_attributes |= self. #name . write_attributes(writer, settings)?;
+            let mut writer = crate::io::SeparatedWriter::new(&mut *writer, wrote_any_attributes);
+            wrote_any_attributes |= self. #name . write_attributes(&mut writer, settings)?;
         }}
     }
+
+    pub fn generate_pair_expr(&self) -> Expr {
+        let name = &self.name;
+        parse_quote! {{
+            self. #name . write_attribute_pairs(attribute_pairs, settings)?;
+        }}
+    }
+
+    pub fn generate_read_expr(&self) -> Expr {
+        let name = &self.name;
+        parse_quote! {
+            if self. #name . read_attribute(name, value)? {
+                return Ok(true);
+            }
+        }
+    }
 }
@@ -1,4 +1,4 @@
-use bundle::{XmlAttribute, XmlAttributeBundle};
+use bundle::{RenameRule, XmlAttribute, XmlAttributeBundle};
 use proc_macro::TokenStream as TokenStream1;
 
 use proc_macro::TokenStream;
@@ -20,14 +20,31 @@ pub fn attrib_bundle(input: TokenStream1) -> TokenStream1 {
         Data::Union(_) => todo!("union not supported"),
     };
 
-    let entries: Vec<_> = fields.iter().filter_map(XmlAttribute::new).collect();
-    let checks: Vec<Expr> = match flatten_result_vec(entries) {
-        Ok(it) => it
-            .into_iter()
-            .map(XmlAttribute::generate_write_expr)
-            .collect(),
+    let rename = match RenameRule::from_container(&input) {
+        Ok(it) => it,
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+
+    let entries: Vec<_> = fields
+        .iter()
+        .filter_map(|field| XmlAttribute::new(field, rename))
+        .collect();
+    let entries: Vec<XmlAttribute> = match flatten_result_vec(entries) {
+        Ok(it) => it,
         Err(err) => return TokenStream::from(err.to_compile_error()),
     };
+    let read_arms: Vec<Arm> = entries
+        .iter()
+        .map(XmlAttribute::generate_read_arm)
+        .collect();
+    let checks: Vec<Expr> = entries
+        .iter()
+        .map(XmlAttribute::generate_write_expr)
+        .collect();
+    let pair_checks: Vec<Expr> = entries
+        .iter()
+        .map(XmlAttribute::generate_pair_expr)
+        .collect();
 
     let struct_name = &input.ident;
     let struct_generics = input.generics.clone();
@@ -61,6 +78,8 @@ pub fn attrib_bundle(input: TokenStream1) -> TokenStream1 {
     };
 
     let bundle_exprs: Vec<Expr> = bundles.iter().map(|it| it.generate_write_expr()).collect();
+    let bundle_pair_exprs: Vec<Expr> = bundles.iter().map(|it| it.generate_pair_expr()).collect();
+    let bundle_read_exprs: Vec<Expr> = bundles.iter().map(|it| it.generate_read_expr()).collect();
 
     let result = quote! {
         impl #struct_generics crate::io::AttributeBundle for #struct_name #generic_names {
@@ -80,6 +99,41 @@ pub fn attrib_bundle(input: TokenStream1) -> TokenStream1 {
                 )*
                 Ok(wrote_any_attributes)
             }
+
+            #[cfg(feature = "write")]
+            #[allow(unused)]
+            fn write_attribute_pairs(
+                &self,
+                attribute_pairs: &mut Vec<crate::io::AttributePair>,
+                settings: &crate::io::WriteSettings,
+            ) -> std::io::Result<()> {
+                #(
+                    #pair_checks
+                )*
+                #(
+                    #bundle_pair_exprs
+                )*
+                Ok(())
+            }
+
+            #[cfg(feature = "read")]
+            #[allow(unused)]
+            fn read_attribute(
+                &mut self,
+                name: &str,
+                value: &str,
+            ) -> Result<bool, crate::error::InvalidAttributeValue> {
+                match name {
+                    #(
+                        #read_arms
+                    )*
+                    _ => {}
+                }
+                #(
+                    #bundle_read_exprs
+                )*
+                Ok(false)
+            }
         }
     };
 
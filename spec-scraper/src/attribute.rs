@@ -1,5 +1,4 @@
 use std::{
-    cell::OnceCell,
     ptr::{addr_of, addr_of_mut},
     rc::Rc,
 };
@@ -48,8 +47,7 @@ impl AttributeValue {
             let value = el
                 .children()
                 .rev()
-                .skip_while(|it| !it.value().is_element())
-                .next()
+                .find(|it| it.value().is_element())
                 .expect("unable to locate attribute value element");
 
             let value = value.first_child().expect("empty attribute value tag");
@@ -98,18 +96,15 @@ impl AttributeValue {
                 .expect("missing table value")
                 .descendants()
                 .filter_map(|desc| {
-                    desc.value()
-                        .as_text()
-                        .map(|text| {
-                            let text = text.to_string();
-                            let trimmed = text.trim();
-                            if !trimmed.is_empty() {
-                                Some(trimmed.to_string())
-                            } else {
-                                None
-                            }
-                        })
-                        .flatten()
+                    desc.value().as_text().and_then(|text| {
+                        let text = text.to_string();
+                        let trimmed = text.trim();
+                        if !trimmed.is_empty() {
+                            Some(trimmed.to_string())
+                        } else {
+                            None
+                        }
+                    })
                 })
                 .collect::<Vec<_>>()
                 .join(" ");
@@ -262,7 +257,7 @@ impl AttributeGroup {
         };
 
         attribute_groups().push(Rc::new(result));
-        unsafe { GROUPS.last().unwrap().clone() }
+        unsafe { (*addr_of!(GROUPS)).last().unwrap().clone() }
     }
 }
 
@@ -276,7 +271,7 @@ pub(crate) mod serialize_group_named {
 
     use super::{attribute_groups, AttributeGroup};
 
-    pub fn serialize<S>(value: &Vec<Rc<AttributeGroup>>, s: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(value: &[Rc<AttributeGroup>], s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
@@ -308,7 +303,7 @@ pub(crate) mod serialize_group_named {
             .collect();
 
         if bundles.len() == bundles_names.len() {
-            return Ok(bundles);
+            Ok(bundles)
         } else {
             Err(de::Error::custom("couldn't find required bundles in cache"))
         }
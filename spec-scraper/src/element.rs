@@ -1,6 +1,6 @@
-use std::{cell::OnceCell, collections::HashMap, rc::Rc};
+use std::{cell::OnceCell, collections::HashMap, ptr::addr_of, rc::Rc};
 
-use scraper::{ElementRef, Html, Selector};
+use scraper::{ElementRef, Selector};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -125,7 +125,7 @@ impl SummaryInfo {
 
             match li.children().count() {
                 1 => {
-                    let attr = AttributeInterface::from_spanned_link(link_el.clone());
+                    let attr = AttributeInterface::from_spanned_link(link_el);
                     context_attributes.push(attr);
                 }
                 2 => {
@@ -162,7 +162,7 @@ fn element_summary(tag_name: impl AsRef<str>) -> Option<ElementRef<'static>> {
     static mut SECTIONS: OnceCell<HashMap<String, ElementRef<'static>>> = OnceCell::new();
 
     unsafe {
-        SECTIONS.get_or_init(|| {
+        (*addr_of!(SECTIONS)).get_or_init(|| {
             log::debug!("Processing element summaries...");
             let mut sections = HashMap::new();
 
@@ -189,7 +189,7 @@ fn element_summary(tag_name: impl AsRef<str>) -> Option<ElementRef<'static>> {
     }
 
     unsafe {
-        SECTIONS
+        (*addr_of!(SECTIONS))
             .get()
             .and_then(|it| it.get(tag_name.as_ref()))
             .cloned()
@@ -1,12 +1,9 @@
-use std::{
-    cell::OnceCell, collections::HashMap, hash::Hash, mem::MaybeUninit, path::PathBuf, sync::Once,
-};
+use std::{cell::OnceCell, collections::HashMap, path::PathBuf, ptr::addr_of};
 
 use attribute::attribute_groups;
 use ego_tree::NodeRef;
-use element::{get_element_info, unquote};
+use element::get_element_info;
 use scraper::{Element, ElementRef, Html, Node, Selector};
-use serde::Serialize;
 
 use crate::element::ElementInterface;
 
@@ -26,7 +23,7 @@ fn spec() -> &'static Html {
     static mut PAGE_CACHE: OnceCell<Html> = OnceCell::new();
 
     unsafe {
-        PAGE_CACHE.get_or_init(|| {
+        (*addr_of!(PAGE_CACHE)).get_or_init(|| {
             let local = match std::fs::read_to_string(SPEC_CACHE_PATH) {
                 Ok(it) => {
                     log::info!("Loaded cached specification.");
@@ -57,6 +54,7 @@ fn spec_chapter(id: impl AsRef<str>) -> Option<ElementRef<'static>> {
         .next()
 }
 
+#[allow(dead_code)]
 fn heading_section(id: impl AsRef<str>) -> Option<Vec<NodeRef<'static, Node>>> {
     let spec = spec();
     let title = spec
@@ -164,10 +162,6 @@ fn scrape() {
     std::fs::write(ATTR_GROUP_PATH, attr_groups).expect("unable to store attribute groups");
 }
 
-fn generate() {
-    
-}
-
 fn main() {
     env_logger::init();
 
@@ -0,0 +1,581 @@
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    common::{ConditionalProcessing, CoreAttributes, DelimitedValues, XLinkAttributes},
+    error::{InvalidAttributeValue, InvalidClockValue, InvalidKeyword},
+    io::*,
+    math::{Number, PositiveNumber},
+    script::AnimationEvents,
+};
+
+/// Duration or point in time used by animation timing attributes.
+///
+/// Parsed from any of the clock value forms (`02:30:03`, `00:10.25`, `1.5min`,
+/// `200ms`, `3`), but always written as a number of seconds (e.g. `90s`),
+/// formatted with the [`WriteSettings`] number settings.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#ClockValueSyntax)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClockValue {
+    Seconds(Number),
+    Indefinite,
+}
+
+impl ClockValue {
+    /// Returns the number of seconds this value represents, or `None` if it's
+    /// indefinite.
+    pub fn as_seconds(&self) -> Option<Number> {
+        match self {
+            ClockValue::Seconds(seconds) => Some(*seconds),
+            ClockValue::Indefinite => None,
+        }
+    }
+}
+
+impl Default for ClockValue {
+    /// Zero seconds.
+    #[inline]
+    fn default() -> Self {
+        ClockValue::Seconds(0.0)
+    }
+}
+
+impl Display for ClockValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClockValue::Seconds(seconds) => write!(f, "{}s", seconds),
+            ClockValue::Indefinite => f.write_str("indefinite"),
+        }
+    }
+}
+
+impl FromStr for ClockValue {
+    type Err = InvalidClockValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidClockValue(s.to_string());
+        let value = s.trim();
+        if value == "indefinite" {
+            return Ok(ClockValue::Indefinite);
+        }
+
+        // two digit minutes of a clock value
+        let minutes = |part: &str| -> Result<Number, InvalidClockValue> {
+            if part.len() != 2 || !part.bytes().all(|it| it.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            match part.parse::<Number>() {
+                Ok(it) if it < 60.0 => Ok(it),
+                _ => Err(invalid()),
+            }
+        };
+        // two digit seconds of a clock value, optionally with a fraction
+        let seconds = |part: &str| -> Result<Number, InvalidClockValue> {
+            let whole = match part.split_once('.') {
+                Some((whole, fraction))
+                    if !fraction.is_empty() && fraction.bytes().all(|it| it.is_ascii_digit()) =>
+                {
+                    whole
+                }
+                Some(_) => return Err(invalid()),
+                None => part,
+            };
+            // whole seconds have the same format and range as minutes
+            minutes(whole)?;
+            part.parse().map_err(|_| invalid())
+        };
+        let digits = |part: &str| -> Result<Number, InvalidClockValue> {
+            if part.is_empty() || !part.bytes().all(|it| it.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse().map_err(|_| invalid())
+        };
+
+        let parts: Vec<&str> = value.split(':').collect();
+        let seconds = match parts.as_slice() {
+            [hours, mins, secs] => digits(hours)? * 3600.0 + minutes(mins)? * 60.0 + seconds(secs)?,
+            [mins, secs] => minutes(mins)? * 60.0 + seconds(secs)?,
+            [timecount] => {
+                // milliseconds are divided as 0.001 isn't exactly representable
+                let (count, scale, divisor) = if let Some(it) = timecount.strip_suffix("ms") {
+                    (it, 1.0, 1000.0)
+                } else if let Some(it) = timecount.strip_suffix("min") {
+                    (it, 60.0, 1.0)
+                } else if let Some(it) = timecount.strip_suffix('h') {
+                    (it, 3600.0, 1.0)
+                } else if let Some(it) = timecount.strip_suffix('s') {
+                    (it, 1.0, 1.0)
+                } else {
+                    (*timecount, 1.0, 1.0)
+                };
+                if !count.bytes().all(|it| it.is_ascii_digit() || it == b'.') {
+                    return Err(invalid());
+                }
+                count.parse::<Number>().map_err(|_| invalid())? * scale / divisor
+            }
+            _ => return Err(invalid()),
+        };
+
+        Ok(ClockValue::Seconds(seconds))
+    }
+}
+
+impl_from_str_conversions!(ClockValue);
+impl_serde_string!(ClockValue);
+
+impl FromStringUnsafe for ClockValue {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for ClockValue {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            ClockValue::Seconds(seconds) => {
+                write_number(writer, *seconds, settings)?;
+                writer.write_all(b"s")
+            }
+            ClockValue::Indefinite => writer.write_all(b"indefinite"),
+        }
+    }
+}
+
+impl_writable_attribute_value!(ClockValue);
+
+/// Number of times an animation is repeated.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#RepeatCountAttribute)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RepeatCount {
+    /// Animation is repeated indefinitely, until the document is closed.
+    Indefinite,
+    /// Number of iterations, which may be fractional.
+    Count(PositiveNumber),
+}
+
+impl FromStr for RepeatCount {
+    type Err = InvalidAttributeValue;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "indefinite" => Ok(RepeatCount::Indefinite),
+            count => count
+                .parse()
+                .ok()
+                .and_then(PositiveNumber::new)
+                .map(RepeatCount::Count)
+                .ok_or_else(|| InvalidAttributeValue::new("repeatCount", s)),
+        }
+    }
+}
+
+impl_from_str_conversions!(RepeatCount);
+impl_serde_string!(RepeatCount);
+
+impl Display for RepeatCount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RepeatCount::Indefinite => f.write_str("indefinite"),
+            RepeatCount::Count(count) => count.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for RepeatCount {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            RepeatCount::Indefinite => writer.write_all(b"indefinite"),
+            RepeatCount::Count(count) => crate::io::Writable::write_to(count, writer, settings),
+        }
+    }
+}
+
+/// Whether the effect of an animation is kept after it ends.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#FillAttribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationFill {
+    /// Final value of the animation is kept.
+    Freeze,
+    /// Animation effect is removed once it ends.
+    #[default]
+    Remove,
+}
+
+impl FromStr for AnimationFill {
+    type Err = InvalidKeyword;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "freeze" => Ok(AnimationFill::Freeze),
+            "remove" => Ok(AnimationFill::Remove),
+            _ => Err(InvalidKeyword(s.to_string())),
+        }
+    }
+}
+
+impl_from_str_conversions!(AnimationFill);
+impl_serde_string!(AnimationFill);
+
+impl Display for AnimationFill {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            AnimationFill::Freeze => "freeze",
+            AnimationFill::Remove => "remove",
+        })
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for AnimationFill {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            AnimationFill::Freeze => writer.write_all(b"freeze"),
+            AnimationFill::Remove => writer.write_all(b"remove"),
+        }
+    }
+}
+
+/// Type of transformation animated by `<animateTransform>`.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AnimateTransformElementTypeAttribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransformType {
+    #[default]
+    Translate,
+    Scale,
+    Rotate,
+    SkewX,
+    SkewY,
+}
+
+impl FromStr for TransformType {
+    type Err = InvalidKeyword;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "translate" => Ok(TransformType::Translate),
+            "scale" => Ok(TransformType::Scale),
+            "rotate" => Ok(TransformType::Rotate),
+            "skewX" => Ok(TransformType::SkewX),
+            "skewY" => Ok(TransformType::SkewY),
+            _ => Err(InvalidKeyword(s.to_string())),
+        }
+    }
+}
+
+impl_from_str_conversions!(TransformType);
+impl_serde_string!(TransformType);
+
+impl Display for TransformType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TransformType::Translate => "translate",
+            TransformType::Scale => "scale",
+            TransformType::Rotate => "rotate",
+            TransformType::SkewX => "skewX",
+            TransformType::SkewY => "skewY",
+        })
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for TransformType {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            TransformType::Translate => writer.write_all(b"translate"),
+            TransformType::Scale => writer.write_all(b"scale"),
+            TransformType::Rotate => writer.write_all(b"rotate"),
+            TransformType::SkewX => writer.write_all(b"skewX"),
+            TransformType::SkewY => writer.write_all(b"skewY"),
+        }
+    }
+}
+
+/// Attributes identifying the attribute or property that's animated.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#TargetAttributes)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationTarget<'a> {
+    /// Name of the target attribute.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AttributeNameAttribute)
+    #[xml_attribute {
+        name: "attributeName",
+    }]
+    pub attribute_name: Option<Cow<'a, str>>,
+
+    /// Namespace of the target attribute: `CSS`, `XML` or `auto`.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AttributeTypeAttribute)
+    #[xml_attribute {
+        name: "attributeType",
+    }]
+    pub attribute_type: Option<Cow<'a, str>>,
+}
+
+/// Attributes controlling when and for how long an animation runs.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#TimingAttributes)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationTiming<'a> {
+    /// Semicolon separated list of times or events that begin the animation.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#BeginAttribute)
+    #[xml_attribute]
+    pub begin: Option<Cow<'a, str>>,
+
+    /// Simple duration of the animation.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#DurAttribute)
+    #[xml_attribute]
+    pub dur: Option<ClockValue>,
+
+    /// Semicolon separated list of times or events that end the animation.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#EndAttribute)
+    #[xml_attribute]
+    pub end: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#RepeatCountAttribute)
+    #[xml_attribute {
+        name: "repeatCount",
+    }]
+    pub repeat_count: Option<RepeatCount>,
+
+    /// Total duration of repetition.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#RepeatDurAttribute)
+    #[xml_attribute {
+        name: "repeatDur",
+    }]
+    pub repeat_dur: Option<ClockValue>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#FillAttribute)
+    #[xml_attribute]
+    pub fill: Option<AnimationFill>,
+}
+
+/// Attributes defining the values an animation interpolates between.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#ValueAttributes)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationValues<'a> {
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#FromAttribute)
+    #[xml_attribute]
+    pub from: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#ToAttribute)
+    #[xml_attribute]
+    pub to: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#ByAttribute)
+    #[xml_attribute]
+    pub by: Option<Cow<'a, str>>,
+
+    /// List of values used over the course of the animation, overrides
+    /// `from`, `to` and `by`.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#ValuesAttribute)
+    #[xml_attribute]
+    pub values: Option<DelimitedValues<';'>>,
+
+    /// List of time values in `[0, 1]` range, one for each of `values`.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#KeyTimesAttribute)
+    #[xml_attribute {
+        name: "keyTimes",
+    }]
+    pub key_times: Option<DelimitedValues<';'>>,
+}
+
+/// Animates a single attribute or property over time.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AnimateElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementAnimate<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Animation event attributes.
+    #[xml_attribute_bundle]
+    pub animation_event: Box<AnimationEvents<'a>>,
+
+    /// XLink attributes referencing the animated element, which defaults to
+    /// the parent element.
+    #[xml_attribute_bundle]
+    pub xlink: Box<XLinkAttributes<'a>>,
+
+    /// Animated attribute.
+    #[xml_attribute_bundle]
+    pub target: Box<AnimationTarget<'a>>,
+
+    /// Animation timing.
+    #[xml_attribute_bundle]
+    pub timing: Box<AnimationTiming<'a>>,
+
+    /// Animated values.
+    #[xml_attribute_bundle]
+    pub values: Box<AnimationValues<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementAnimate<'_> {
+    fn size_hint(&self) -> usize {
+        "<animate/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<animate")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "animate", settings)
+    }
+}
+
+/// Sets the value of an attribute for a specified duration.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#SetElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementSet<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Animation event attributes.
+    #[xml_attribute_bundle]
+    pub animation_event: Box<AnimationEvents<'a>>,
+
+    /// XLink attributes referencing the animated element, which defaults to
+    /// the parent element.
+    #[xml_attribute_bundle]
+    pub xlink: Box<XLinkAttributes<'a>>,
+
+    /// Animated attribute.
+    #[xml_attribute_bundle]
+    pub target: Box<AnimationTarget<'a>>,
+
+    /// Animation timing.
+    #[xml_attribute_bundle]
+    pub timing: Box<AnimationTiming<'a>>,
+
+    /// Value the attribute is set to.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#SetElementToAttribute)
+    #[xml_attribute]
+    pub to: Option<Cow<'a, str>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementSet<'_> {
+    fn size_hint(&self) -> usize {
+        "<set/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<set")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "set", settings)
+    }
+}
+
+/// Animates a transformation attribute of the target element.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AnimateTransformElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementAnimateTransform<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Animation event attributes.
+    #[xml_attribute_bundle]
+    pub animation_event: Box<AnimationEvents<'a>>,
+
+    /// XLink attributes referencing the animated element, which defaults to
+    /// the parent element.
+    #[xml_attribute_bundle]
+    pub xlink: Box<XLinkAttributes<'a>>,
+
+    /// Animated attribute.
+    #[xml_attribute_bundle]
+    pub target: Box<AnimationTarget<'a>>,
+
+    /// Animation timing.
+    #[xml_attribute_bundle]
+    pub timing: Box<AnimationTiming<'a>>,
+
+    /// Animated values.
+    #[xml_attribute_bundle]
+    pub values: Box<AnimationValues<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#AnimateTransformElementTypeAttribute)
+    #[xml_attribute {
+        name: "type",
+    }]
+    pub transform_type: Option<TransformType>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementAnimateTransform<'_> {
+    fn size_hint(&self) -> usize {
+        "<animateTransform/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<animateTransform")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "animateTransform", settings)
+    }
+}
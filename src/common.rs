@@ -1,8 +1,13 @@
-use std::{borrow::Cow, fmt::Write, marker::PhantomData, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, fmt::Display, marker::PhantomData, str::FromStr};
 
 use structuredvg_macros::BundleAttributes;
 
-use crate::{error::InvalidLanguageTag, io::*, style::DeclarationList};
+use crate::{
+    error::{InvalidAttributeName, InvalidKeyword, InvalidLanguageTag},
+    io::*,
+    math::{Bounded, Coordinate, NonNegativeLength, Point, Rect, Vector},
+    style::DeclarationList,
+};
 
 /// Represents a collection of values `V` stored as a `DELIMITER` separated list
 /// in the document.
@@ -33,18 +38,30 @@ impl<const DELIMITER: char, V: AttributeValue> DelimitedValues<DELIMITER, V> {
         }
     }
 
+    /// Constructs a list containing all `values` in iteration order.
+    pub fn with_values(values: impl IntoIterator<Item = V>) -> Self {
+        let mut result = Self::new();
+        for value in values {
+            result.push(value);
+        }
+        result
+    }
+
+    /// Removes all values from this list, keeping allocated capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.inner.clear();
+    }
+
     pub fn push(&mut self, value: V) {
         if !self.inner.is_empty() {
-            self.inner
-                .write_char(DELIMITER)
-                .expect("unable to push delimiter");
+            self.inner.push(DELIMITER);
         }
 
         match value.as_str() {
-            Some(it) => self.inner.write_str(it),
-            None => self.inner.write_str(value.to_string().as_str()),
+            Some(it) => self.inner.push_str(it),
+            None => self.inner.push_str(value.to_string().as_str()),
         }
-        .expect("unable to push value");
     }
 
     /// # Safety
@@ -55,12 +72,10 @@ impl<const DELIMITER: char, V: AttributeValue> DelimitedValues<DELIMITER, V> {
     /// return an error while parsing it.
     pub unsafe fn push_str(&mut self, value: &str) {
         if !self.inner.is_empty() {
-            self.inner
-                .write_char(DELIMITER)
-                .expect("unable to push delimiter");
+            self.inner.push(DELIMITER);
         }
 
-        self.inner.write_str(value).expect("unable to push value");
+        self.inner.push_str(value);
     }
 
     // TODO: Track DelimitedValues indices?
@@ -97,10 +112,8 @@ impl<const DELIMITER: char, V: AttributeValue> DelimitedValues<DELIMITER, V> {
                 // Not at the end
                 end += 1;
             }
-            if start != 0 {
-                // Not at the beginning
-                start -= 1;
-            }
+            // Include the preceding delimiter if not at the beginning
+            start = start.saturating_sub(1);
             self.inner.drain(start..end).count();
             true
         } else {
@@ -117,14 +130,169 @@ impl<const DELIMITER: char, V: AttributeValue> DelimitedValues<DELIMITER, V> {
         position.is_some()
     }
 
+    /// Returns the first value in this list, or `None` if it's empty.
+    pub fn first(&self) -> Option<&str> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        self.inner.split(DELIMITER).next()
+    }
+
+    /// Returns the last value in this list, or `None` if it's empty.
+    pub fn last(&self) -> Option<&str> {
+        if self.inner.is_empty() {
+            return None;
+        }
+        self.inner.rsplit(DELIMITER).next()
+    }
+
+    /// Returns byte range of value at `index` in the backing string.
+    fn value_range(&self, index: usize) -> Option<std::ops::Range<usize>> {
+        let mut start = 0;
+        for (i, value) in self.iter().enumerate() {
+            if i == index {
+                return Some(start..start + value.len());
+            }
+            start += value.len() + DELIMITER.len_utf8();
+        }
+        None
+    }
+
+    /// Returns the value at `index`, or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.iter().nth(index)
+    }
+
+    /// Inserts `value` at position `index`, shifting all values after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, same as [`Vec::insert`].
+    pub fn insert(&mut self, index: usize, value: V) {
+        let value = match value.as_str() {
+            Some(it) => Cow::Borrowed(it),
+            None => Cow::Owned(value.to_string()),
+        };
+
+        match self.value_range(index) {
+            Some(range) => {
+                self.inner.insert(range.start, DELIMITER);
+                self.inner.insert_str(range.start, &value);
+            }
+            None => {
+                let len = self.iter().count();
+                assert!(
+                    index == len,
+                    "insertion index (is {index}) should be <= len (is {len})"
+                );
+                if !self.inner.is_empty() {
+                    self.inner.push(DELIMITER);
+                }
+                self.inner.push_str(&value);
+            }
+        }
+    }
+
+    /// Removes and returns the value at `index`, or `None` if it's out of
+    /// bounds.
+    pub fn remove_at(&mut self, index: usize) -> Option<V> {
+        let range = self.value_range(index)?;
+        let value = self.inner[range.clone()].to_string();
+
+        let drained = if range.end < self.inner.len() {
+            // take following delimiter
+            range.start..range.end + DELIMITER.len_utf8()
+        } else if range.start > 0 {
+            // last value; take preceding delimiter
+            range.start - DELIMITER.len_utf8()..range.end
+        } else {
+            range
+        };
+        self.inner.drain(drained);
+
+        Some(unsafe {
+            // SAFETY: All values stored in the container come from
+            // V::to_string()
+            FromStringUnsafe::from(value)
+        })
+    }
+
+    /// Retains only the values for which `f` returns `true`, preserving their
+    /// order.
+    pub fn retain(&mut self, mut f: impl FnMut(&str) -> bool) {
+        let mut retained = String::with_capacity(self.inner.len());
+        for value in self.iter() {
+            if !f(value) {
+                continue;
+            }
+            if !retained.is_empty() {
+                retained.push(DELIMITER);
+            }
+            retained.push_str(value);
+        }
+        self.inner = retained;
+    }
+
+    /// Removes repeated values from this list, keeping only their first
+    /// occurrence.
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.retain(|value| seen.insert(value.to_string()));
+    }
+
+    /// Appends copies of all values of `other` to the end of this list.
+    pub fn append(&mut self, other: &Self) {
+        if other.inner.is_empty() {
+            return;
+        }
+        if !self.inner.is_empty() {
+            self.inner.push(DELIMITER);
+        }
+        self.inner.push_str(&other.inner);
+    }
+
+    /// Moves all values of this list to the end of `other`, leaving this list
+    /// empty.
+    pub fn drain_into(&mut self, other: &mut Self) {
+        other.append(self);
+        self.clear();
+    }
+
+    /// Returns `true` if both lists contain the same values, regardless of
+    /// their order and repetition.
+    ///
+    /// This matches how class lists are interpreted, while `==` compares the
+    /// lists exactly.
+    pub fn eq_as_set(&self, other: &Self) -> bool {
+        let values: HashSet<&str> = self.iter().collect();
+        values == other.iter().collect()
+    }
+
+    /// Returns a copy of this list with its values sorted lexicographically.
+    pub fn to_sorted(&self) -> Self {
+        let mut values: Vec<&str> = self.iter().collect();
+        values.sort_unstable();
+        let mut delimiter = [0; 4];
+        DelimitedValues {
+            inner: values.join(DELIMITER.encode_utf8(&mut delimiter)),
+            _phantom: PhantomData,
+        }
+    }
+
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
-        self.inner.split(DELIMITER)
+        // splitting an empty string yields a single empty token
+        let values = if self.inner.is_empty() {
+            None
+        } else {
+            Some(self.inner.split(DELIMITER))
+        };
+        values.into_iter().flatten()
     }
 
     #[inline]
     pub fn iter_values(&self) -> impl Iterator<Item = V> + '_ {
-        self.inner.split(DELIMITER).map(|it| unsafe {
+        self.iter().map(|it| unsafe {
             // SAFETY: All values stored in the container come from
             // V::to_string()
             FromStringUnsafe::from(it.to_string())
@@ -132,15 +300,147 @@ impl<const DELIMITER: char, V: AttributeValue> DelimitedValues<DELIMITER, V> {
     }
 }
 
+impl<const DELIMITER: char, V: AttributeValue + FromStr + PartialEq> DelimitedValues<DELIMITER, V> {
+    /// Returns `true` if any value of this list parses into a value equal to
+    /// `value`.
+    ///
+    /// Unlike [`contains`](DelimitedValues::contains), values are compared as
+    /// `V`, so differently written equal values (e.g. `1` and `1.0`) match.
+    /// Values that fail to parse never match.
+    pub fn contains_value(&self, value: &V) -> bool {
+        self.position_of(value).is_some()
+    }
+
+    /// Removes the first value of this list that parses into a value equal
+    /// to `value`, or returns `false` if there's none.
+    ///
+    /// See [`contains_value`](DelimitedValues::contains_value).
+    pub fn remove_value(&mut self, value: &V) -> bool {
+        match self.position_of(value) {
+            Some(index) => self.remove_at(index).is_some(),
+            None => false,
+        }
+    }
+
+    fn position_of(&self, value: &V) -> Option<usize> {
+        self.iter()
+            .position(|it| it.trim().parse::<V>().is_ok_and(|it| it == *value))
+    }
+}
+
+impl<const DELIMITER: char, V: AttributeValue> IntoIterator for DelimitedValues<DELIMITER, V> {
+    type Item = V;
+    type IntoIter = IntoValues<DELIMITER, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoValues {
+            finished: self.inner.is_empty(),
+            inner: self.inner,
+            position: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+/// Owning iterator over values stored in [`DelimitedValues`].
+///
+/// Created by [`DelimitedValues::into_iter`].
+#[derive(Debug, Clone)]
+pub struct IntoValues<const DELIMITER: char, V: AttributeValue> {
+    inner: String,
+    position: usize,
+    finished: bool,
+    _phantom: PhantomData<V>,
+}
+
+impl<const DELIMITER: char, V: AttributeValue> Iterator for IntoValues<DELIMITER, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let rest = &self.inner[self.position..];
+        let value = match rest.find(DELIMITER) {
+            Some(end) => {
+                self.position += end + DELIMITER.len_utf8();
+                &rest[..end]
+            }
+            None => {
+                self.finished = true;
+                rest
+            }
+        };
+
+        Some(unsafe {
+            // SAFETY: All values stored in the container come from
+            // V::to_string()
+            FromStringUnsafe::from(value.to_string())
+        })
+    }
+}
+
+#[cfg(feature = "read")]
+impl<const DELIMITER: char, V: AttributeValue + Readable> Readable
+    for DelimitedValues<DELIMITER, V>
+{
+    /// Reads a list of values, dropping whitespace around them and empty
+    /// values.
+    fn read(value: &str) -> Option<Self> {
+        let mut result = DelimitedValues::new();
+        let values = value
+            .split(DELIMITER)
+            .flat_map(|it| {
+                if DELIMITER.is_whitespace() {
+                    it.split_whitespace().collect::<Vec<_>>()
+                } else {
+                    vec![it.trim()]
+                }
+            })
+            .filter(|it| !it.is_empty());
+        for value in values {
+            result.push(V::read(value)?);
+        }
+        Some(result)
+    }
+}
+
 impl<const DELIMITER: char, V: AttributeValue> AsRef<str> for DelimitedValues<DELIMITER, V> {
     fn as_ref(&self) -> &str {
         &self.inner
     }
 }
 
-impl<const DELIMITER: char, V: AttributeValue> ToString for DelimitedValues<DELIMITER, V> {
-    fn to_string(&self) -> String {
-        self.inner.clone()
+impl<const DELIMITER: char, V: AttributeValue> Display for DelimitedValues<DELIMITER, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+/// Serialized as the backing string.
+#[cfg(feature = "serde")]
+impl<const DELIMITER: char, V: AttributeValue> serde::Serialize for DelimitedValues<DELIMITER, V> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+/// Deserialized from a string with values separated by `DELIMITER`, each of
+/// which must parse as `V`.
+#[cfg(feature = "serde")]
+impl<'de, const DELIMITER: char, V> serde::Deserialize<'de> for DelimitedValues<DELIMITER, V>
+where
+    V: AttributeValue + FromStr,
+    V::Err: Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <Cow<'de, str>>::deserialize(deserializer)?;
+        let mut result = DelimitedValues::new();
+        for value in value.split(DELIMITER).filter(|it| !it.is_empty()) {
+            result.push(value.parse().map_err(serde::de::Error::custom)?);
+        }
+        Ok(result)
     }
 }
 
@@ -151,9 +451,109 @@ impl<const DELIMITER: char, V: AttributeValue> crate::io::Writable
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &crate::io::WriteSettings,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        write_str_escaped(writer, self.as_ref(), settings)
+    }
+}
+
+/// [`DelimitedValues`] written with a custom `separator` between values, such
+/// as `", "` instead of a bare `','`.
+///
+/// Values are stored the same way as in [`DelimitedValues`], which is
+/// accessible through [`Deref`](std::ops::Deref), so the separator only
+/// affects output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeparatedValues<const DELIMITER: char, V: AttributeValue = String> {
+    values: DelimitedValues<DELIMITER, V>,
+    separator: &'static str,
+}
+
+impl<const DELIMITER: char, V: AttributeValue> SeparatedValues<DELIMITER, V> {
+    #[inline]
+    pub fn new(separator: &'static str) -> Self {
+        SeparatedValues {
+            values: DelimitedValues::new(),
+            separator,
+        }
+    }
+
+    /// Constructs a list containing all `values` in iteration order.
+    #[inline]
+    pub fn with_values(separator: &'static str, values: impl IntoIterator<Item = V>) -> Self {
+        SeparatedValues {
+            values: DelimitedValues::with_values(values),
+            separator,
+        }
+    }
+
+    /// Parses a list of values separated by any run of `DELIMITER`
+    /// characters and whitespace surrounding them.
+    pub fn parse(separator: &'static str, s: &str) -> Result<Self, V::Err>
+    where
+        V: FromStr,
+    {
+        let mut result = Self::new(separator);
+        for value in s.split(DELIMITER).map(str::trim).filter(|it| !it.is_empty()) {
+            result.values.push(value.parse()?);
+        }
+        Ok(result)
+    }
+
+    #[inline]
+    pub fn separator(&self) -> &'static str {
+        self.separator
+    }
+
+    #[inline]
+    pub fn set_separator(&mut self, separator: &'static str) {
+        self.separator = separator;
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> DelimitedValues<DELIMITER, V> {
+        self.values
+    }
+}
+
+impl<const DELIMITER: char, V: AttributeValue> std::ops::Deref for SeparatedValues<DELIMITER, V> {
+    type Target = DelimitedValues<DELIMITER, V>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl<const DELIMITER: char, V: AttributeValue> std::ops::DerefMut
+    for SeparatedValues<DELIMITER, V>
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.values
+    }
+}
+
+#[cfg(feature = "write")]
+impl<const DELIMITER: char, V: AttributeValue> crate::io::Writable
+    for SeparatedValues<DELIMITER, V>
+{
+    fn size_hint(&self) -> usize {
+        self.values.inner.len()
+            + self.values.iter().count().saturating_sub(1) * self.separator.len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
     ) -> std::io::Result<()> {
-        writer.write(self.as_ref().as_bytes())?;
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                write_str_escaped(writer, self.separator, settings)?;
+            }
+            write_str_escaped(writer, value, settings)?;
+        }
         Ok(())
     }
 }
@@ -171,6 +571,295 @@ pub enum XmlSpace {
     Preserve,
 }
 
+impl XmlSpace {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            XmlSpace::Default => "default",
+            XmlSpace::Preserve => "preserve",
+        }
+    }
+}
+
+impl Display for XmlSpace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for XmlSpace {
+    type Err = InvalidKeyword;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(XmlSpace::Default),
+            "preserve" => Ok(XmlSpace::Preserve),
+            _ => Err(InvalidKeyword(s.to_string())),
+        }
+    }
+}
+
+impl_from_str_conversions!(XmlSpace);
+impl_serde_string!(XmlSpace);
+
+impl FromStringUnsafe for XmlSpace {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for XmlSpace {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(self.as_str().as_bytes())
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        Some(XmlSpace::as_str(self))
+    }
+}
+
+impl_writable_attribute_value!(XmlSpace);
+
+/// Boolean attribute value.
+///
+/// Written as `true`/`false` or `1`/`0`, depending on
+/// [`WriteSettings::bool_style`]. All four spellings are accepted when
+/// parsing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct XmlBool(pub bool);
+
+impl From<bool> for XmlBool {
+    #[inline]
+    fn from(value: bool) -> Self {
+        XmlBool(value)
+    }
+}
+
+impl From<XmlBool> for bool {
+    #[inline]
+    fn from(value: XmlBool) -> Self {
+        value.0
+    }
+}
+
+impl Display for XmlBool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for XmlBool {
+    type Err = InvalidKeyword;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" | "1" => Ok(XmlBool(true)),
+            "false" | "0" => Ok(XmlBool(false)),
+            _ => Err(InvalidKeyword(s.to_string())),
+        }
+    }
+}
+
+impl_from_str_conversions!(XmlBool);
+impl_serde_string!(XmlBool);
+
+impl FromStringUnsafe for XmlBool {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for XmlBool {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        let value: &[u8] = match (settings.bool_style, self.0) {
+            (BoolStyle::Keyword, true) => b"true",
+            (BoolStyle::Keyword, false) => b"false",
+            (BoolStyle::Numeric, true) => b"1",
+            (BoolStyle::Numeric, false) => b"0",
+        };
+        writer.write_all(value)
+    }
+}
+
+impl_writable_attribute_value!(XmlBool);
+
+keyword_enum! {
+    /// Coordinate system used by attributes and content of referenced
+    /// elements.
+    ///
+    /// Shared by `clipPathUnits`, `maskUnits`, `maskContentUnits`,
+    /// `filterUnits`, `primitiveUnits`, `patternUnits`,
+    /// `patternContentUnits`, `gradientUnits` and `markerUnits` attributes.
+    /// Defaults differ between them, see [`Units::default_for`].
+    pub enum Units {
+        /// Values represent values in the current user coordinate system in
+        /// place at the time when the element is referenced.
+        UserSpaceOnUse => "userSpaceOnUse",
+        /// Values represent fractions or percentages of the bounding box of
+        /// the element the referencing element is applied to.
+        #[default]
+        ObjectBoundingBox => "objectBoundingBox",
+        /// Values are scaled by the `stroke-width` of the element referencing
+        /// a marker.
+        ///
+        /// Only valid for `markerUnits`.
+        StrokeWidth => "strokeWidth",
+    }
+}
+
+impl Units {
+    /// Returns the value used when an `attribute` isn't specified, or `None`
+    /// if it isn't a units attribute.
+    pub fn default_for(attribute: &str) -> Option<Units> {
+        match attribute {
+            "clipPathUnits" | "maskContentUnits" | "primitiveUnits" | "patternContentUnits" => {
+                Some(Units::UserSpaceOnUse)
+            }
+            "maskUnits" | "filterUnits" | "patternUnits" | "gradientUnits" => {
+                Some(Units::ObjectBoundingBox)
+            }
+            "markerUnits" => Some(Units::StrokeWidth),
+            _ => None,
+        }
+    }
+}
+
+/// Alignment of the `viewBox` within the viewport.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Don't force uniform scaling.
+    None,
+    XMinYMin,
+    XMidYMin,
+    XMaxYMin,
+    XMinYMid,
+    #[default]
+    XMidYMid,
+    XMaxYMid,
+    XMinYMax,
+    XMidYMax,
+    XMaxYMax,
+}
+
+impl Align {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Align::None => "none",
+            Align::XMinYMin => "xMinYMin",
+            Align::XMidYMin => "xMidYMin",
+            Align::XMaxYMin => "xMaxYMin",
+            Align::XMinYMid => "xMinYMid",
+            Align::XMidYMid => "xMidYMid",
+            Align::XMaxYMid => "xMaxYMid",
+            Align::XMinYMax => "xMinYMax",
+            Align::XMidYMax => "xMidYMax",
+            Align::XMaxYMax => "xMaxYMax",
+        }
+    }
+}
+
+/// Whether the `viewBox` is scaled to fit inside the viewport or to cover it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MeetOrSlice {
+    #[default]
+    Meet,
+    Slice,
+}
+
+/// Specifies whether uniform scaling is forced when the `viewBox` aspect ratio
+/// doesn't match the aspect ratio of the viewport.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PreserveAspectRatio {
+    /// Only applies to `<image>` elements referencing an image that has its
+    /// own `preserveAspectRatio`.
+    pub defer: bool,
+    pub align: Align,
+    pub meet_or_slice: MeetOrSlice,
+}
+
+impl FromStr for PreserveAspectRatio {
+    type Err = InvalidKeyword;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidKeyword(s.to_string());
+        let mut words = s.split_whitespace().peekable();
+        let defer = words.next_if_eq(&"defer").is_some();
+        let align = match words.next().ok_or_else(invalid)? {
+            "none" => Align::None,
+            "xMinYMin" => Align::XMinYMin,
+            "xMidYMin" => Align::XMidYMin,
+            "xMaxYMin" => Align::XMaxYMin,
+            "xMinYMid" => Align::XMinYMid,
+            "xMidYMid" => Align::XMidYMid,
+            "xMaxYMid" => Align::XMaxYMid,
+            "xMinYMax" => Align::XMinYMax,
+            "xMidYMax" => Align::XMidYMax,
+            "xMaxYMax" => Align::XMaxYMax,
+            _ => return Err(invalid()),
+        };
+        let meet_or_slice = match words.next() {
+            None | Some("meet") => MeetOrSlice::Meet,
+            Some("slice") => MeetOrSlice::Slice,
+            Some(_) => return Err(invalid()),
+        };
+        if words.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(PreserveAspectRatio {
+            defer,
+            align,
+            meet_or_slice,
+        })
+    }
+}
+
+impl_from_str_conversions!(PreserveAspectRatio);
+impl_serde_string!(PreserveAspectRatio);
+
+impl Display for PreserveAspectRatio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.defer {
+            f.write_str("defer ")?;
+        }
+        f.write_str(self.align.as_str())?;
+        if self.meet_or_slice == MeetOrSlice::Slice {
+            f.write_str(" slice")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for PreserveAspectRatio {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        if self.defer {
+            writer.write_all(b"defer ")?;
+        }
+        writer.write_all(self.align.as_str().as_bytes())?;
+        if self.meet_or_slice == MeetOrSlice::Slice {
+            writer.write_all(b" slice")?;
+        }
+        Ok(())
+    }
+}
+
 /// Type safe representation of a language tag.
 ///
 /// Value should follow [RFC 5646](https://www.rfc-editor.org/info/rfc5646).
@@ -192,11 +881,40 @@ impl<'a> LanguageTag<'a> {
     pub fn new(value: impl Into<Cow<'a, str>>) -> Result<Self, InvalidLanguageTag> {
         Ok(LanguageTag(value.into()))
     }
+
+    /// Returns this tag with subtags in their canonical case, as recommended
+    /// by [RFC 5646](https://www.rfc-editor.org/rfc/rfc5646#section-2.1.1).
+    ///
+    /// Two letter region subtags are uppercased (`us` → `US`), four letter
+    /// script subtags are titlecased (`hant` → `Hant`), and all other subtags
+    /// are lowercased. Subtags following a singleton, such as extensions and
+    /// private use subtags (`x-...`), are always lowercased.
+    pub fn canonicalize(&self) -> LanguageTag<'static> {
+        let mut result = String::with_capacity(self.0.len());
+        let mut after_singleton = false;
+        for (i, subtag) in self.0.split('-').enumerate() {
+            if i > 0 {
+                result.push('-');
+            }
+            if after_singleton || i == 0 || subtag.len() == 1 {
+                after_singleton |= subtag.len() == 1;
+                result.push_str(&subtag.to_ascii_lowercase());
+            } else if subtag.len() == 2 {
+                result.push_str(&subtag.to_ascii_uppercase());
+            } else if subtag.len() == 4 && subtag.bytes().all(|it| it.is_ascii_alphabetic()) {
+                result.push_str(&subtag[..1].to_ascii_uppercase());
+                result.push_str(&subtag[1..].to_ascii_lowercase());
+            } else {
+                result.push_str(&subtag.to_ascii_lowercase());
+            }
+        }
+        LanguageTag(Cow::Owned(result))
+    }
 }
 
-impl ToString for LanguageTag<'_> {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl Display for LanguageTag<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -207,28 +925,50 @@ impl FromStr for LanguageTag<'_> {
     }
 }
 
+// InvalidLanguageTag is reserved for validation that may be added later
+#[allow(clippy::infallible_try_from)]
+impl TryFrom<&str> for LanguageTag<'_> {
+    type Error = InvalidLanguageTag;
+
+    #[inline]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(feature = "read")]
+impl Readable for LanguageTag<'_> {
+    #[inline]
+    fn read(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
 impl FromStringUnsafe for LanguageTag<'_> {
     unsafe fn from(value: String) -> Self {
         LanguageTag(Cow::Owned(value))
     }
 }
 
+impl_serde_string!(LanguageTag<'a>);
+
 impl<'a> AttributeValue for LanguageTag<'a> {
     #[cfg(feature = "write")]
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &WriteSettings,
+        settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        writer.write(self.0.as_bytes())?;
-        Ok(())
+        write_str_escaped(writer, &self.0, settings)
     }
 
     fn as_str(&self) -> Option<&str> {
-        return None;
+        Some(&self.0)
     }
 }
 
+impl_writable_attribute_value!(LanguageTag<'_>);
+
 impl std::ops::Deref for LanguageTag<'_> {
     type Target = str;
 
@@ -244,11 +984,10 @@ impl std::ops::Deref for LanguageTag<'_> {
 /// sections of [SVG 1.1](https://www.w3.org/TR/SVG11/intro.html#TermCoreAttributes)
 /// specification.
 #[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoreAttributes<'a> {
     /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#IDAttribute)
-    #[xml_attribute{
-        transform: id.as_bytes()
-    }]
+    #[xml_attribute]
     pub id: Option<Cow<'a, str>>,
 
     /// This attribute is part of SVG 2 specification, but it's part of
@@ -257,9 +996,7 @@ pub struct CoreAttributes<'a> {
     ///
     /// [SVG 2 documentation](https://www.w3.org/TR/SVG/struct.html#tabindexattribute)
     #[cfg(feature = "html")]
-    #[xml_attribute{
-        transform: tabindex.to_string().as_bytes()
-    }]
+    #[xml_attribute]
     pub tabindex: Option<isize>,
 
     /// Specifies the primary language for the element's contents and for any of
@@ -268,7 +1005,7 @@ pub struct CoreAttributes<'a> {
     /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#XMLLangAttribute)
     #[xml_attribute{
         name: "xml:lang",
-        transform: xml_lang.as_bytes()
+        html_name: "lang",
     }]
     pub xml_lang: Option<LanguageTag<'a>>,
 
@@ -323,6 +1060,47 @@ pub struct CoreAttributes<'a> {
     pub other: Vec<NonStandardAttribute<'a>>,
 }
 
+#[cfg(feature = "html")]
+impl<'a> CoreAttributes<'a> {
+    fn data_position(&self, key: &str) -> Option<usize> {
+        self.data
+            .iter()
+            .position(|it| it.name.strip_prefix("data-") == Some(key))
+    }
+
+    /// Returns the value of `data-*` attribute with provided `key`.
+    ///
+    /// `key` shouldn't contain a "data-" prefix.
+    pub fn get_data(&self, key: &str) -> Option<&str> {
+        self.data_position(key)
+            .map(|index| self.data[index].value.as_ref())
+    }
+
+    /// Sets the value of `data-*` attribute with provided `key`, replacing
+    /// the existing value or appending a new attribute if there isn't one.
+    ///
+    /// `key` shouldn't contain a "data-" prefix.
+    pub fn set_data(&mut self, key: &str, value: impl Into<Cow<'a, str>>) {
+        match self.data_position(key) {
+            Some(index) => self.data[index].value = value.into(),
+            None => self.data.push(DataAttribute::new(key, value)),
+        }
+    }
+
+    /// Removes `data-*` attribute with provided `key`.
+    ///
+    /// Returns `true` if the attribute was present.
+    pub fn remove_data(&mut self, key: &str) -> bool {
+        match self.data_position(key) {
+            Some(index) => {
+                self.data.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
 /// Represents a `data-*` attribute.
 ///
 /// `name` should must be at least one character long, must be
@@ -332,11 +1110,13 @@ pub struct CoreAttributes<'a> {
 /// For details see [HTML5 specification](https://www.w3.org/TR/2014/CR-html5-20140204/dom.html#embedding-custom-non-visible-data-with-the-data-*-attributes).
 #[cfg(feature = "html")]
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DataAttribute<'a> {
     pub name: Cow<'a, str>,
     pub value: Cow<'a, str>,
 }
 
+#[cfg(feature = "html")]
 impl<'a> DataAttribute<'a> {
     /// Creates a new data-* attribute from provided `name` and `value`.
     ///
@@ -358,9 +1138,31 @@ impl<'a> Attribute<'a> for DataAttribute<'a> {
     fn write_attribute<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &WriteSettings,
+        settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        write!(writer, "{}=\"{}\"", self.name, self.value)
+        writer.write_all(self.name.as_bytes())?;
+        writer.write_all(b"=")?;
+        settings.write_attribute_quote(writer)?;
+        write_str_escaped(writer, &self.value, settings)?;
+        settings.write_attribute_quote(writer)
+    }
+
+    #[cfg(feature = "write")]
+    fn write_attribute_pair(&self, settings: &WriteSettings) -> std::io::Result<AttributePair> {
+        let mut value = Vec::with_capacity(self.value.len());
+        write_str_escaped(&mut value, &self.value, settings)?;
+        Ok((Cow::Owned(self.name.as_bytes().to_vec()), value))
+    }
+
+    #[cfg(feature = "read")]
+    fn read_attribute(name: &str, value: &str) -> Option<Self> {
+        if !name.starts_with("data-") {
+            return None;
+        }
+        Some(DataAttribute {
+            name: Cow::Owned(name.to_string()),
+            value: Cow::Owned(value.to_string()),
+        })
     }
 
     fn name(&'a self) -> &'a str {
@@ -377,12 +1179,64 @@ impl<'a> Attribute<'a> for DataAttribute<'a> {
 }
 
 /// Contains a non-standard attribute.
+///
+/// `name` must match the XML [`Name`](https://www.w3.org/TR/xml/#NT-Name)
+/// production or written output won't be well formed. Fields are public for
+/// convenience, use [`NonStandardAttribute::try_new`] to have the name
+/// checked. `value` is escaped when written.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonStandardAttribute<'a> {
     pub name: Cow<'a, str>,
     pub value: Cow<'a, str>,
 }
 
+impl<'a> NonStandardAttribute<'a> {
+    /// Creates a new attribute from provided `name` and `value`, checking that
+    /// `name` is a valid XML name.
+    pub fn try_new(
+        name: impl Into<Cow<'a, str>>,
+        value: impl Into<Cow<'a, str>>,
+    ) -> Result<Self, InvalidAttributeName> {
+        let name = name.into();
+        if !is_xml_name(&name) {
+            return Err(InvalidAttributeName(name.into_owned()));
+        }
+        Ok(NonStandardAttribute {
+            name,
+            value: value.into(),
+        })
+    }
+}
+
+fn is_xml_name_start_char(c: char) -> bool {
+    matches!(c,
+        ':' | 'A'..='Z' | '_' | 'a'..='z'
+        | '\u{C0}'..='\u{D6}' | '\u{D8}'..='\u{F6}' | '\u{F8}'..='\u{2FF}'
+        | '\u{370}'..='\u{37D}' | '\u{37F}'..='\u{1FFF}' | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}' | '\u{2C00}'..='\u{2FEF}' | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}' | '\u{FDF0}'..='\u{FFFD}' | '\u{10000}'..='\u{EFFFF}'
+    )
+}
+
+fn is_xml_name_char(c: char) -> bool {
+    is_xml_name_start_char(c)
+        || matches!(c,
+            '-' | '.' | '0'..='9' | '\u{B7}'
+            | '\u{300}'..='\u{36F}' | '\u{203F}'..='\u{2040}'
+        )
+}
+
+/// Checks whether `name` matches the XML [`Name`](https://www.w3.org/TR/xml/#NT-Name)
+/// production.
+pub(crate) fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) if is_xml_name_start_char(first) => chars.all(is_xml_name_char),
+        _ => false,
+    }
+}
+
 impl<'a> Attribute<'a> for NonStandardAttribute<'a> {
     type Value = Cow<'a, str>;
 
@@ -390,9 +1244,20 @@ impl<'a> Attribute<'a> for NonStandardAttribute<'a> {
     fn write_attribute<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &WriteSettings,
+        settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        write!(writer, "{}=\"{}\"", self.name, self.value)
+        writer.write_all(self.name.as_bytes())?;
+        writer.write_all(b"=")?;
+        settings.write_attribute_quote(writer)?;
+        write_str_escaped(writer, &self.value, settings)?;
+        settings.write_attribute_quote(writer)
+    }
+
+    #[cfg(feature = "write")]
+    fn write_attribute_pair(&self, settings: &WriteSettings) -> std::io::Result<AttributePair> {
+        let mut value = Vec::with_capacity(self.value.len());
+        write_str_escaped(&mut value, &self.value, settings)?;
+        Ok((Cow::Owned(self.name.as_bytes().to_vec()), value))
     }
 
     fn name(&'a self) -> &'a str {
@@ -408,11 +1273,48 @@ impl<'a> Attribute<'a> for NonStandardAttribute<'a> {
     }
 }
 
+/// Position and size of the rectangular region an element occupies.
+///
+/// Shared by `<rect>`, `<image>`, `<use>`, `<svg>`, `<pattern>`, `<mask>` and
+/// `<foreignObject>` elements. Specified values are always written, including
+/// zero `width` and `height` which disable rendering of some elements.
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositionSize {
+    #[xml_attribute]
+    pub x: Option<Coordinate>,
+    #[xml_attribute]
+    pub y: Option<Coordinate>,
+    #[xml_attribute]
+    pub width: Option<NonNegativeLength>,
+    #[xml_attribute]
+    pub height: Option<NonNegativeLength>,
+}
+
+impl Bounded for PositionSize {
+    /// Returns the described region, or `None` if `width` or `height` isn't
+    /// specified, any of the lengths uses font-relative units, or the position
+    /// is a percentage.
+    fn bounding_box(&self) -> Option<Rect> {
+        let coordinate = |it: Option<Coordinate>| match it {
+            Some(coordinate) => coordinate.to_user_units(),
+            None => Some(0.0),
+        };
+        let min = Point::new(coordinate(self.x)?, coordinate(self.y)?);
+        let size = Vector::new(
+            self.width?.to_user_units()?,
+            self.height?.to_user_units()?,
+        );
+        Some(Rect::from_points(min, min + size))
+    }
+}
+
 /// These arguments provide an ability to specify alternate viewing depending on
 /// the capabilities of a given user agent or the user's language.
 ///
 /// For details see [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#ConditionalProcessing).
 #[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionalProcessing<'a> {
     /// List of required user agent features.
     ///
@@ -442,3 +1344,120 @@ pub struct ConditionalProcessing<'a> {
     }]
     pub system_language: Option<DelimitedValues<',', LanguageTag<'a>>>,
 }
+
+impl ConditionalProcessing<'_> {
+    /// Returns `true` if all required features and extensions are supported
+    /// by `env`, and `systemLanguage` matches one of its user languages.
+    ///
+    /// Attributes that aren't specified are always satisfied, while ones
+    /// that are specified but empty never are.
+    pub fn is_satisfied(&self, env: &ProcessingEnv) -> bool {
+        fn all_supported(
+            required: &Option<DelimitedValues<' '>>,
+            supported: &HashSet<Cow<'_, str>>,
+        ) -> bool {
+            let required = match required {
+                Some(it) => it,
+                None => return true,
+            };
+            let mut values = required.iter().filter(|it| !it.is_empty()).peekable();
+            values.peek().is_some() && values.all(|it| supported.contains(it))
+        }
+
+        all_supported(&self.required_features, &env.features)
+            && all_supported(&self.required_extensions, &env.extensions)
+            && self.matches_language(env)
+    }
+
+    /// Returns `true` if `systemLanguage` matches one of the user languages in
+    /// `env`, or if it isn't specified.
+    ///
+    /// A language matches if it's equal to a user language, or if a user
+    /// language is its prefix followed by a `-` (e.g. `en` matches `en-US`).
+    /// Comparison is case-insensitive.
+    pub fn matches_language(&self, env: &ProcessingEnv) -> bool {
+        let system_language = match &self.system_language {
+            Some(it) => it,
+            None => return true,
+        };
+
+        system_language.iter().map(str::trim).any(|language| {
+            env.languages.iter().any(|user| {
+                let user = user.0.as_ref();
+                match language.get(..user.len()) {
+                    Some(prefix) => {
+                        prefix.eq_ignore_ascii_case(user)
+                            && matches!(language.as_bytes().get(user.len()), None | Some(b'-'))
+                    }
+                    None => false,
+                }
+            })
+        })
+    }
+}
+
+/// Capabilities and preferences of the user agent [`ConditionalProcessing`]
+/// attributes are evaluated against.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessingEnv<'a> {
+    /// Supported [feature strings](https://www.w3.org/TR/SVG11/feature.html).
+    pub features: HashSet<Cow<'a, str>>,
+    /// IRIs of supported language extensions.
+    pub extensions: HashSet<Cow<'a, str>>,
+    /// Languages preferred by the user.
+    pub languages: Vec<LanguageTag<'a>>,
+}
+
+/// XLink attributes used by elements that reference other resources.
+///
+/// For details see [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkRefAttrs).
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XLinkAttributes<'a> {
+    /// [IRI reference](https://www.w3.org/TR/SVG11/linking.html#IRIReference)
+    /// to the referenced resource.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkHrefAttribute)
+    #[xml_attribute {
+        name: "xlink:href",
+    }]
+    pub href: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkTypeAttribute)
+    #[xml_attribute {
+        name: "xlink:type",
+    }]
+    pub link_type: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkRoleAttribute)
+    #[xml_attribute {
+        name: "xlink:role",
+    }]
+    pub role: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkArcRoleAttribute)
+    #[xml_attribute {
+        name: "xlink:arcrole",
+    }]
+    pub arcrole: Option<Cow<'a, str>>,
+
+    /// Human-readable description of the link.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkTitleAttribute)
+    #[xml_attribute {
+        name: "xlink:title",
+    }]
+    pub title: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkShowAttribute)
+    #[xml_attribute {
+        name: "xlink:show",
+    }]
+    pub show: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#XLinkActuateAttribute)
+    #[xml_attribute {
+        name: "xlink:actuate",
+    }]
+    pub actuate: Option<Cow<'a, str>>,
+}
@@ -0,0 +1,767 @@
+use std::{borrow::Cow, sync::Arc};
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    animation::{ElementAnimate, ElementAnimateTransform, ElementSet},
+    common::{
+        ConditionalProcessing, CoreAttributes, NonStandardAttribute, PositionSize,
+        PreserveAspectRatio, ProcessingEnv, XLinkAttributes,
+    },
+    filter::ElementFilter,
+    marker::ElementMarker,
+    math::{Bounded, Rect, ViewBox},
+    paint::{ElementClipPath, ElementMask},
+    path::ElementPath,
+    script::{ElementScript, GraphicalEvents},
+    shape::{
+        ElementCircle, ElementEllipse, ElementLine, ElementPolygon, ElementPolyline, ElementRect,
+    },
+    style::{ElementStyle, ReferenceProperties},
+    svg::ElementSvg,
+};
+
+/// Any element that can be a child of a
+/// [container element](https://www.w3.org/TR/SVG11/intro.html#TermContainerElement).
+///
+/// Element types defined outside of this crate can be placed into containers
+/// through [`Element::Custom`], see [`DynElement`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Element<'a> {
+    Path(ElementPath<'a>),
+    Group(ElementGroup<'a>),
+    Rect(ElementRect<'a>),
+    Circle(ElementCircle<'a>),
+    Ellipse(ElementEllipse<'a>),
+    Line(ElementLine<'a>),
+    Polyline(ElementPolyline<'a>),
+    Polygon(ElementPolygon<'a>),
+    ClipPath(ElementClipPath<'a>),
+    Mask(ElementMask<'a>),
+    Filter(ElementFilter<'a>),
+    Symbol(ElementSymbol<'a>),
+    Marker(ElementMarker<'a>),
+    Anchor(ElementAnchor<'a>),
+    Svg(ElementSvg<'a>),
+    ForeignObject(ElementForeignObject<'a>),
+    Switch(ElementSwitch<'a>),
+    Script(ElementScript<'a>),
+    Style(ElementStyle<'a>),
+    Animate(ElementAnimate<'a>),
+    Set(ElementSet<'a>),
+    AnimateTransform(ElementAnimateTransform<'a>),
+    Fragment(Fragment<'a>),
+    Unknown(ElementUnknown<'a>),
+    /// Custom elements can't be serialized or deserialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(Arc<dyn DynElement + 'a>),
+}
+
+impl<'a> Element<'a> {
+    /// Wraps an element type defined outside of this crate.
+    pub fn custom(element: impl DynElement + 'a) -> Self {
+        Element::Custom(Arc::new(element))
+    }
+
+    /// Returns conditional processing attributes of this element, or `None`
+    /// if it doesn't support them.
+    pub fn conditional_processing(&self) -> Option<&ConditionalProcessing<'a>> {
+        match self {
+            Element::Path(it) => Some(&it.conditional_processing),
+            Element::Group(it) => Some(&it.conditional_processing),
+            Element::Rect(it) => Some(&it.conditional_processing),
+            Element::Circle(it) => Some(&it.conditional_processing),
+            Element::Ellipse(it) => Some(&it.conditional_processing),
+            Element::Line(it) => Some(&it.conditional_processing),
+            Element::Polyline(it) => Some(&it.conditional_processing),
+            Element::Polygon(it) => Some(&it.conditional_processing),
+            Element::ClipPath(it) => Some(&it.conditional_processing),
+            Element::Mask(it) => Some(&it.conditional_processing),
+            Element::Anchor(it) => Some(&it.conditional_processing),
+            Element::Svg(it) => Some(&it.conditional_processing),
+            Element::ForeignObject(it) => Some(&it.conditional_processing),
+            Element::Switch(it) => Some(&it.conditional_processing),
+            Element::Animate(it) => Some(&it.conditional_processing),
+            Element::Set(it) => Some(&it.conditional_processing),
+            Element::AnimateTransform(it) => Some(&it.conditional_processing),
+            Element::Filter(_)
+            | Element::Symbol(_)
+            | Element::Marker(_)
+            | Element::Script(_)
+            | Element::Style(_)
+            | Element::Fragment(_)
+            | Element::Unknown(_)
+            | Element::Custom(_) => None,
+        }
+    }
+}
+
+impl Bounded for Element<'_> {
+    /// Returns the bounding box of rendered geometry of this element.
+    ///
+    /// Elements which aren't rendered directly, such as clipping paths,
+    /// markers and symbols, have no bounds. Nested `<svg>` elements establish
+    /// a new viewport and aren't supported either.
+    fn bounding_box(&self) -> Option<Rect> {
+        match self {
+            Element::Path(it) => it.bounding_box(),
+            Element::Group(it) => it.bounding_box(),
+            Element::Rect(it) => it.bounding_box(),
+            Element::Circle(it) => it.bounding_box(),
+            Element::Ellipse(it) => it.bounding_box(),
+            Element::Line(it) => it.bounding_box(),
+            Element::Polyline(it) => it.bounding_box(),
+            Element::Polygon(it) => it.bounding_box(),
+            Element::Anchor(it) => it.bounding_box(),
+            Element::Switch(it) => it.bounding_box(),
+            Element::Fragment(it) => it.bounding_box(),
+            Element::Custom(it) => it.bounding_box(),
+            Element::ForeignObject(it) => it.position_size.bounding_box(),
+            Element::ClipPath(_)
+            | Element::Mask(_)
+            | Element::Filter(_)
+            | Element::Symbol(_)
+            | Element::Marker(_)
+            | Element::Svg(_)
+            | Element::Script(_)
+            | Element::Style(_)
+            | Element::Animate(_)
+            | Element::Set(_)
+            | Element::AnimateTransform(_)
+            | Element::Unknown(_) => None,
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for Element<'_> {
+    fn size_hint(&self) -> usize {
+        match self {
+            Element::Path(it) => it.size_hint(),
+            Element::Group(it) => it.size_hint(),
+            Element::Rect(it) => it.size_hint(),
+            Element::Circle(it) => it.size_hint(),
+            Element::Ellipse(it) => it.size_hint(),
+            Element::Line(it) => it.size_hint(),
+            Element::Polyline(it) => it.size_hint(),
+            Element::Polygon(it) => it.size_hint(),
+            Element::ClipPath(it) => it.size_hint(),
+            Element::Mask(it) => it.size_hint(),
+            Element::Filter(it) => it.size_hint(),
+            Element::Symbol(it) => it.size_hint(),
+            Element::Marker(it) => it.size_hint(),
+            Element::Anchor(it) => it.size_hint(),
+            Element::Svg(it) => it.size_hint(),
+            Element::ForeignObject(it) => it.size_hint(),
+            Element::Switch(it) => it.size_hint(),
+            Element::Script(it) => it.size_hint(),
+            Element::Style(it) => it.size_hint(),
+            Element::Animate(it) => it.size_hint(),
+            Element::Set(it) => it.size_hint(),
+            Element::AnimateTransform(it) => it.size_hint(),
+            Element::Fragment(it) => it.size_hint(),
+            Element::Unknown(it) => it.size_hint(),
+            Element::Custom(it) => DynElement::size_hint(it.as_ref()),
+        }
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Element::Path(it) => it.write_to(writer, settings),
+            Element::Group(it) => it.write_to(writer, settings),
+            Element::Rect(it) => it.write_to(writer, settings),
+            Element::Circle(it) => it.write_to(writer, settings),
+            Element::Ellipse(it) => it.write_to(writer, settings),
+            Element::Line(it) => it.write_to(writer, settings),
+            Element::Polyline(it) => it.write_to(writer, settings),
+            Element::Polygon(it) => it.write_to(writer, settings),
+            Element::ClipPath(it) => it.write_to(writer, settings),
+            Element::Mask(it) => it.write_to(writer, settings),
+            Element::Filter(it) => it.write_to(writer, settings),
+            Element::Symbol(it) => it.write_to(writer, settings),
+            Element::Marker(it) => it.write_to(writer, settings),
+            Element::Anchor(it) => it.write_to(writer, settings),
+            Element::Svg(it) => it.write_to(writer, settings),
+            Element::ForeignObject(it) => it.write_to(writer, settings),
+            Element::Switch(it) => it.write_to(writer, settings),
+            Element::Script(it) => it.write_to(writer, settings),
+            Element::Style(it) => it.write_to(writer, settings),
+            Element::Animate(it) => it.write_to(writer, settings),
+            Element::Set(it) => it.write_to(writer, settings),
+            Element::AnimateTransform(it) => it.write_to(writer, settings),
+            Element::Fragment(it) => it.write_to(writer, settings),
+            Element::Unknown(it) => it.write_to(writer, settings),
+            Element::Custom(it) => it.write_dyn(writer, settings),
+        }
+    }
+}
+
+/// Object safe element interface, allowing element types defined outside of
+/// this crate to be mixed with the ones it provides.
+///
+/// [`Element`] is an enum, so it can be matched on, inspected and modified,
+/// and it's what documents are read into. Custom elements stored in
+/// [`Element::Custom`] or a `Vec<Box<dyn DynElement>>` are opaque instead:
+/// they can only be written, and writing them goes through dynamic dispatch.
+///
+/// Implementations are usually thin wrappers around [`Writable`]:
+///
+/// ```ignore
+/// impl DynElement for MyElement {
+///     fn tag_name(&self) -> &str {
+///         "my-element"
+///     }
+///
+///     fn write_dyn(
+///         &self,
+///         mut writer: &mut dyn std::io::Write,
+///         settings: &WriteSettings,
+///     ) -> std::io::Result<()> {
+///         self.write_to(&mut writer, settings)
+///     }
+/// }
+/// ```
+///
+/// [`Writable`]: crate::io::Writable
+pub trait DynElement: std::fmt::Debug + Send + Sync {
+    /// Returns the name of the element tag.
+    fn tag_name(&self) -> &str;
+
+    /// Writes this element to a writer.
+    #[cfg(feature = "write")]
+    fn write_dyn(
+        &self,
+        writer: &mut dyn std::io::Write,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()>;
+
+    /// Returns a rough estimate of the number of bytes this element writes.
+    ///
+    /// See [`Writable::size_hint`](crate::io::Writable::size_hint).
+    #[cfg(feature = "write")]
+    #[inline]
+    fn size_hint(&self) -> usize {
+        0
+    }
+
+    /// Returns the bounding box of rendered geometry of this element, see
+    /// [`Bounded`].
+    #[inline]
+    fn bounding_box(&self) -> Option<Rect> {
+        None
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for dyn DynElement + '_ {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        DynElement::size_hint(self)
+    }
+
+    #[inline]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        self.write_dyn(writer, settings)
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for Box<dyn DynElement + '_> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        DynElement::size_hint(self.as_ref())
+    }
+
+    #[inline]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        self.write_dyn(writer, settings)
+    }
+}
+
+/// Groups its children so they can share attributes.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#GElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementGroup<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    pub children: Vec<Element<'a>>,
+}
+
+impl ElementGroup<'_> {
+    /// Returns a `viewBox` fitting rendered children of this group, or `None`
+    /// if they have no bounds.
+    pub fn compute_view_box(&self) -> Option<ViewBox> {
+        self.children.bounding_box().map(ViewBox::from)
+    }
+}
+
+impl Bounded for ElementGroup<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.children.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementGroup<'_> {
+    fn size_hint(&self) -> usize {
+        "<g></g>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<g")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "g", settings)
+    }
+}
+
+/// Defines a graphical template object which is only rendered when
+/// instantiated by a `<use>` element.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#SymbolElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementSymbol<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#ViewBoxAttribute)
+    #[xml_attribute {
+        name: "viewBox",
+    }]
+    pub view_box: Option<ViewBox>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute)
+    #[xml_attribute {
+        name: "preserveAspectRatio",
+    }]
+    pub preserve_aspect_ratio: Option<PreserveAspectRatio>,
+
+    pub children: Vec<Element<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementSymbol<'_> {
+    fn size_hint(&self) -> usize {
+        "<symbol></symbol>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<symbol")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "symbol", settings)
+    }
+}
+
+/// Embeds content from a different XML namespace, such as XHTML or MathML.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/extend.html#ForeignObjectElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementForeignObject<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Region the foreign content is rendered into.
+    #[xml_attribute_bundle]
+    pub position_size: Box<PositionSize>,
+
+    /// Foreign markup.
+    ///
+    /// It's written verbatim, without any escaping, so it must be well formed
+    /// markup of the target namespace (usually specified with an `xmlns`
+    /// attribute on its root element).
+    pub content: Cow<'a, str>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementForeignObject<'_> {
+    fn size_hint(&self) -> usize {
+        "<foreignObject></foreignObject>".len() + self.content.len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<foreignObject")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        writer.write_all(b">")?;
+        writer.write_all(self.content.as_bytes())?;
+        writer.write_all(b"</foreignObject>")
+    }
+}
+
+/// Renders only the first direct child whose conditional processing
+/// attributes are satisfied.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#SwitchElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementSwitch<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Alternatives, in order of preference.
+    ///
+    /// All of them are written as selection is done by the user agent.
+    pub children: Vec<Element<'a>>,
+}
+
+impl<'a> ElementSwitch<'a> {
+    /// Returns the child a user agent running in `env` would render.
+    ///
+    /// Children of [`Fragment`]s are treated as direct children of the
+    /// switch, and elements without conditional processing attributes always
+    /// match.
+    pub fn select(&self, env: &ProcessingEnv) -> Option<&Element<'a>> {
+        fn find<'e, 'a>(
+            children: &'e [Element<'a>],
+            env: &ProcessingEnv,
+        ) -> Option<&'e Element<'a>> {
+            children.iter().find_map(|child| match child {
+                Element::Fragment(fragment) => find(&fragment.children, env),
+                other => match other.conditional_processing() {
+                    Some(conditions) if !conditions.is_satisfied(env) => None,
+                    _ => Some(other),
+                },
+            })
+        }
+
+        find(&self.children, env)
+    }
+}
+
+impl Bounded for ElementSwitch<'_> {
+    /// Returns the union of bounds of all alternatives, as the rendered one
+    /// depends on the user agent.
+    fn bounding_box(&self) -> Option<Rect> {
+        self.children.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementSwitch<'_> {
+    fn size_hint(&self) -> usize {
+        "<switch></switch>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<switch")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "switch", settings)
+    }
+}
+
+/// Browsing context in which the link target is displayed.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#AElementTargetAttribute)
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Target<'a> {
+    /// `_replace`
+    Replace,
+    /// `_self`
+    Current,
+    /// `_parent`
+    Parent,
+    /// `_top`
+    Top,
+    /// `_blank`
+    Blank,
+    /// Name of a frame.
+    Frame(Cow<'a, str>),
+}
+
+#[cfg(feature = "read")]
+impl crate::io::Readable for Target<'_> {
+    fn read(value: &str) -> Option<Self> {
+        Some(match value {
+            "_replace" => Target::Replace,
+            "_self" => Target::Current,
+            "_parent" => Target::Parent,
+            "_top" => Target::Top,
+            "_blank" => Target::Blank,
+            frame => Target::Frame(Cow::Owned(frame.to_string())),
+        })
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for Target<'_> {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Target::Replace => writer.write_all(b"_replace"),
+            Target::Current => writer.write_all(b"_self"),
+            Target::Parent => writer.write_all(b"_parent"),
+            Target::Top => writer.write_all(b"_top"),
+            Target::Blank => writer.write_all(b"_blank"),
+            Target::Frame(name) => writer.write_all(name.as_bytes()),
+        }
+    }
+}
+
+/// Link around its children.
+///
+/// Per specification, `<a>` elements can't be nested within other `<a>`
+/// elements, but that isn't enforced by this crate.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#AElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementAnchor<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// XLink attributes, `xlink:href` specifies the link destination.
+    #[xml_attribute_bundle]
+    pub xlink: Box<XLinkAttributes<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/linking.html#AElementTargetAttribute)
+    #[xml_attribute]
+    pub target: Option<Target<'a>>,
+
+    pub children: Vec<Element<'a>>,
+}
+
+impl Bounded for ElementAnchor<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.children.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementAnchor<'_> {
+    fn size_hint(&self) -> usize {
+        "<a></a>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<a")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "a", settings)
+    }
+}
+
+/// Sequence of sibling elements written without an enclosing element.
+///
+/// Useful as a return value of functions producing several elements that
+/// should be placed directly into the parent container.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fragment<'a> {
+    pub children: Vec<Element<'a>>,
+}
+
+impl Bounded for Fragment<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.children.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for Fragment<'_> {
+    fn size_hint(&self) -> usize {
+        crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 {
+                settings.write_line_start(writer)?;
+            }
+            child.write_to(writer, settings)?;
+        }
+        Ok(())
+    }
+}
+
+/// Element which isn't supported by this crate.
+///
+/// Preserves the tag, attributes and content of unrecognized markup so it can
+/// be written back without losing them. Comments and processing instructions
+/// aren't preserved.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementUnknown<'a> {
+    /// Qualified name of the element, including the namespace prefix if any.
+    pub tag: String,
+    pub attributes: Vec<NonStandardAttribute<'a>>,
+    /// Child elements and character data, in document order.
+    pub children: Vec<UnknownContent<'a>>,
+}
+
+impl ElementUnknown<'_> {
+    /// Returns `true` if any of the children is character data.
+    pub fn has_text(&self) -> bool {
+        self.children
+            .iter()
+            .any(|it| matches!(it, UnknownContent::Text(_)))
+    }
+}
+
+/// Child node of an [`ElementUnknown`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnknownContent<'a> {
+    Element(Element<'a>),
+    /// Unescaped character data.
+    Text(Cow<'a, str>),
+}
+
+impl<'a> From<Element<'a>> for UnknownContent<'a> {
+    #[inline]
+    fn from(value: Element<'a>) -> Self {
+        UnknownContent::Element(value)
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for UnknownContent<'_> {
+    fn size_hint(&self) -> usize {
+        match self {
+            UnknownContent::Element(it) => it.size_hint(),
+            UnknownContent::Text(it) => it.len(),
+        }
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            UnknownContent::Element(it) => it.write_to(writer, settings),
+            UnknownContent::Text(it) => {
+                let mut escaped = crate::io::WriteEscaped::for_text(writer);
+                std::io::Write::write_all(&mut escaped, it.as_bytes())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementUnknown<'_> {
+    fn size_hint(&self) -> usize {
+        "<></>".len() + self.tag.len() * 2 + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<")?;
+        writer.write_all(self.tag.as_bytes())?;
+        crate::io::write_element_attributes(&self.attributes, writer, settings)?;
+        if !self.has_text() {
+            return crate::io::write_element_content(&self.children, writer, &self.tag, settings);
+        }
+
+        // indenting mixed content would change its text
+        writer.write_all(b">")?;
+        for child in &self.children {
+            child.write_to(writer, settings)?;
+        }
+        writer.write_all(b"</")?;
+        writer.write_all(self.tag.as_bytes())?;
+        writer.write_all(b">")
+    }
+}
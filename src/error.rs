@@ -1,7 +1,611 @@
+//! Errors produced by this crate.
+//!
+//! Fallible APIs return the most specific error type that describes their
+//! failure, e.g. parsing a [`Length`](crate::math::Length) returns an
+//! [`InvalidLength`] and parsing a [`Color`](crate::style::Color) returns an
+//! [`InvalidColor`]. That way callers can match on the exact cases an API can
+//! produce, and `FromStr` implementations can report errors without
+//! allocating a large enum.
+//!
+//! Every error type converts into [`Error`], so code that doesn't care which
+//! API failed can return `Result<_, Error>` and propagate all of them with
+//! `?`. No API of this crate returns [`Error`] directly.
+
+use std::fmt::Display;
+
+/// Error type unifying all errors produced by this crate.
+///
+/// More specific error types returned by individual APIs all convert into this
+/// type, so it can be used to propagate any of them with `?`.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing a document failed.
+    Io(std::io::Error),
+    /// Language tag is invalid.
+    InvalidLanguageTag(InvalidLanguageTag),
+    /// Textual representation of a value is malformed.
+    Parse(ParseError),
+    /// Value is well formed, but violates constraints of the specification.
+    Validation(ValidationError),
+    /// Reading a document failed.
+    #[cfg(feature = "read-xml")]
+    Read(ReadError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "I/O error: {}", err),
+            Error::InvalidLanguageTag(err) => err.fmt(f),
+            Error::Parse(err) => err.fmt(f),
+            Error::Validation(err) => err.fmt(f),
+            #[cfg(feature = "read-xml")]
+            Error::Read(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::InvalidLanguageTag(err) => Some(err),
+            Error::Parse(err) => Some(err),
+            Error::Validation(err) => Some(err),
+            #[cfg(feature = "read-xml")]
+            Error::Read(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
+
+impl From<InvalidLanguageTag> for Error {
+    fn from(value: InvalidLanguageTag) -> Self {
+        Error::InvalidLanguageTag(value)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::Parse(value)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(value: ValidationError) -> Self {
+        Error::Validation(value)
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<ReadError> for Error {
+    fn from(value: ReadError) -> Self {
+        Error::Read(value)
+    }
+}
+
+/// Errors produced while reading a document with
+/// [`read_document`](crate::svg::read_document).
+#[cfg(feature = "read-xml")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReadError {
+    /// Document isn't well formed XML.
+    Xml(quick_xml::Error),
+    /// Value of a supported attribute is invalid.
+    Attribute(InvalidAttributeValue),
+    /// Root element of the document isn't `<svg>`.
+    UnexpectedRoot(String),
+    /// Document doesn't contain any elements.
+    MissingRoot,
+    /// Document ended before all elements were closed.
+    UnexpectedEof,
+}
+
+#[cfg(feature = "read-xml")]
+impl Display for ReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadError::Xml(err) => write!(f, "malformed XML: {}", err),
+            ReadError::Attribute(err) => err.fmt(f),
+            ReadError::UnexpectedRoot(name) => {
+                write!(f, "expected <svg> root element, found <{}>", name)
+            }
+            ReadError::MissingRoot => f.write_str("document has no root element"),
+            ReadError::UnexpectedEof => f.write_str("unexpected end of document"),
+        }
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl std::error::Error for ReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadError::Xml(err) => Some(err),
+            ReadError::Attribute(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<quick_xml::Error> for ReadError {
+    fn from(value: quick_xml::Error) -> Self {
+        ReadError::Xml(value)
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<quick_xml::events::attributes::AttrError> for ReadError {
+    fn from(value: quick_xml::events::attributes::AttrError) -> Self {
+        ReadError::Xml(value.into())
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<quick_xml::encoding::EncodingError> for ReadError {
+    fn from(value: quick_xml::encoding::EncodingError) -> Self {
+        ReadError::Xml(value.into())
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<std::io::Error> for ReadError {
+    fn from(value: std::io::Error) -> Self {
+        ReadError::Xml(value.into())
+    }
+}
+
+#[cfg(feature = "read-xml")]
+impl From<InvalidAttributeValue> for ReadError {
+    fn from(value: InvalidAttributeValue) -> Self {
+        ReadError::Attribute(value)
+    }
+}
+
+/// Errors caused by malformed textual representation of a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    Keyword(InvalidKeyword),
+    Length(InvalidLength),
+    ClockValue(InvalidClockValue),
+    FuncIri(InvalidFuncIri),
+    Paint(InvalidPaint),
+    Color(InvalidColor),
+    Points(InvalidPoints),
+    AttributeValue(InvalidAttributeValue),
+    Dash(DashError),
+    Var(InvalidVar),
+    #[cfg(feature = "path")]
+    Path(PathError),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ParseError::Keyword(ref err) => err.fmt(f),
+            ParseError::Length(ref err) => err.fmt(f),
+            ParseError::ClockValue(ref err) => err.fmt(f),
+            ParseError::FuncIri(ref err) => err.fmt(f),
+            ParseError::Paint(ref err) => err.fmt(f),
+            ParseError::Color(ref err) => err.fmt(f),
+            ParseError::Points(ref err) => err.fmt(f),
+            ParseError::AttributeValue(ref err) => err.fmt(f),
+            ParseError::Dash(ref err) => err.fmt(f),
+            ParseError::Var(ref err) => err.fmt(f),
+            #[cfg(feature = "path")]
+            ParseError::Path(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Errors caused by well formed values that violate constraints of the
+/// specification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+    Number(InvalidNumber),
+    AttributeName(InvalidAttributeName),
+    Dash(DashError),
+    #[cfg(feature = "path")]
+    Path(PathError),
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ValidationError::Number(ref err) => err.fmt(f),
+            ValidationError::AttributeName(ref err) => err.fmt(f),
+            ValidationError::Dash(ref err) => err.fmt(f),
+            #[cfg(feature = "path")]
+            ValidationError::Path(ref err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Returned when parsing a value that must be one of a fixed set of keywords
+/// from a string that isn't one of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidKeyword(pub String);
+
+impl Display for InvalidKeyword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown keyword: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidKeyword {}
+
+impl From<InvalidKeyword> for Error {
+    fn from(value: InvalidKeyword) -> Self {
+        Error::Parse(ParseError::Keyword(value))
+    }
+}
+
+/// Returned when converting a number that's outside of the range allowed by
+/// the target type, such as [`PositiveNumber`](crate::math::PositiveNumber),
+/// or when parsing one from a string that isn't a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidNumber {
+    NaN,
+    Infinite,
+    Negative,
+    Malformed,
+}
+
+impl Display for InvalidNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidNumber::NaN => f.write_str("number is NaN"),
+            InvalidNumber::Infinite => f.write_str("number is infinite"),
+            InvalidNumber::Negative => f.write_str("number is negative"),
+            InvalidNumber::Malformed => f.write_str("value is not a number"),
+        }
+    }
+}
+
+impl std::error::Error for InvalidNumber {}
+
+impl From<InvalidNumber> for Error {
+    fn from(value: InvalidNumber) -> Self {
+        Error::Validation(ValidationError::Number(value))
+    }
+}
+
+/// Returned when parsing a [`Length`](crate::math::Length),
+/// [`Percentage`](crate::math::Percentage) or
+/// [`Coordinate`](crate::math::Coordinate) from a string that isn't a valid
+/// value, or a length that's out of range for the parsed type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLength(pub String);
+
+impl Display for InvalidLength {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid length: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidLength {}
+
+impl From<InvalidLength> for Error {
+    fn from(value: InvalidLength) -> Self {
+        Error::Parse(ParseError::Length(value))
+    }
+}
+
+/// Returned when parsing a [`ClockValue`](crate::animation::ClockValue) from
+/// a string that isn't a valid clock value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidClockValue(pub String);
+
+impl Display for InvalidClockValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid clock value: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidClockValue {}
+
+impl From<InvalidClockValue> for Error {
+    fn from(value: InvalidClockValue) -> Self {
+        Error::Parse(ParseError::ClockValue(value))
+    }
+}
+
+/// Returned when constructing an attribute with a name that doesn't match the
+/// XML [`Name`](https://www.w3.org/TR/xml/#NT-Name) production.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAttributeName(pub String);
+
+impl Display for InvalidAttributeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid attribute name: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidAttributeName {}
+
+impl From<InvalidAttributeName> for Error {
+    fn from(value: InvalidAttributeName) -> Self {
+        Error::Validation(ValidationError::AttributeName(value))
+    }
+}
+
+/// Returned when parsing a [`FuncIRIOrNone`](crate::style::FuncIRIOrNone)
+/// from a string that's neither `none` nor a `url(...)` reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidFuncIri(pub String);
+
+impl Display for InvalidFuncIri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid IRI reference: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidFuncIri {}
+
+impl From<InvalidFuncIri> for Error {
+    fn from(value: InvalidFuncIri) -> Self {
+        Error::Parse(ParseError::FuncIri(value))
+    }
+}
+
+/// Returned when parsing a [`Paint`](crate::style::Paint) from an empty string
+/// or a malformed paint server reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPaint(pub String);
+
+impl Display for InvalidPaint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid paint: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPaint {}
+
+impl From<InvalidPaint> for Error {
+    fn from(value: InvalidPaint) -> Self {
+        Error::Parse(ParseError::Paint(value))
+    }
+}
+
+/// Returned when parsing a [`Color`](crate::style::Color) from a string that
+/// isn't a supported color notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidColor(pub String);
+
+impl Display for InvalidColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid color: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidColor {}
+
+impl From<InvalidColor> for Error {
+    fn from(value: InvalidColor) -> Self {
+        Error::Parse(ParseError::Color(value))
+    }
+}
+
+/// Returned when parsing a [`Var`](crate::style::Var) from a string that isn't
+/// a `var()` reference to a custom property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidVar(pub String);
+
+impl Display for InvalidVar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid custom property reference: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidVar {}
+
+impl From<InvalidVar> for Error {
+    fn from(value: InvalidVar) -> Self {
+        Error::Parse(ParseError::Var(value))
+    }
+}
+
+/// Returned when parsing a [`PointList`](crate::shape::PointList) from a
+/// string that isn't a list of coordinate pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidPoints(pub String);
+
+impl Display for InvalidPoints {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid point list: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for InvalidPoints {}
+
+impl From<InvalidPoints> for Error {
+    fn from(value: InvalidPoints) -> Self {
+        Error::Parse(ParseError::Points(value))
+    }
+}
+
+/// Returned when reading an attribute whose value isn't valid for the type of
+/// the attribute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAttributeValue {
+    pub name: String,
+    pub value: String,
+}
+
+impl InvalidAttributeValue {
+    pub fn new(name: impl ToString, value: impl ToString) -> Self {
+        InvalidAttributeValue {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    }
+}
+
+impl Display for InvalidAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid value of '{}' attribute: '{}'", self.name, self.value)
+    }
+}
+
+impl std::error::Error for InvalidAttributeValue {}
+
+impl From<InvalidAttributeValue> for Error {
+    fn from(value: InvalidAttributeValue) -> Self {
+        Error::Parse(ParseError::AttributeValue(value))
+    }
+}
+
 /// Represents errors that can occur when reading/constructing an invalid
-/// [LanguageTag](crate::LanguageTag).
+/// [LanguageTag](crate::common::LanguageTag).
 /// 
 /// This enum currently has no variants as the crate doesn't produce these
 /// errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum InvalidLanguageTag {}
+
+impl Display for InvalidLanguageTag {
+    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {}
+    }
+}
+
+impl std::error::Error for InvalidLanguageTag {}
+
+/// Represents errors that can occur when parsing or validating a
+/// [DashArray](crate::style::DashArray).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DashError {
+    /// Value at `index` isn't a number.
+    InvalidNumber(usize),
+    /// Value at `index` is `NaN` or infinite.
+    NotFinite(usize),
+    /// Value at `index` is negative.
+    Negative(usize),
+}
+
+impl Display for DashError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DashError::InvalidNumber(index) => write!(f, "invalid dash length at {}", index),
+            DashError::NotFinite(index) => write!(f, "dash length at {} isn't finite", index),
+            DashError::Negative(index) => write!(f, "dash length at {} is negative", index),
+        }
+    }
+}
+
+impl std::error::Error for DashError {}
+
+impl DashError {
+    /// Returns `true` if this error was caused by a malformed number, and
+    /// `false` if a value is out of range.
+    pub fn is_syntax_error(&self) -> bool {
+        matches!(self, DashError::InvalidNumber(_))
+    }
+}
+
+impl From<DashError> for Error {
+    fn from(value: DashError) -> Self {
+        if value.is_syntax_error() {
+            Error::Parse(ParseError::Dash(value))
+        } else {
+            Error::Validation(ValidationError::Dash(value))
+        }
+    }
+}
+
+/// Represents errors that can occur when parsing or validating
+/// [PathData](crate::path::PathData).
+#[cfg(feature = "path")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathError {
+    /// Path data doesn't begin with a move command.
+    MissingInitialMove,
+    /// Close command at segment `index` isn't preceded by any drawing command
+    /// in its subpath. Only reported by [`PathData::lint`](crate::path::PathData::lint).
+    CloseBeforeDraw(usize),
+    /// Smooth command at segment `index` doesn't follow a command of the same
+    /// curve type. Only reported by [`PathData::lint`](crate::path::PathData::lint).
+    OrphanSmooth(usize),
+    /// Character that's not allowed at byte `position` in path data.
+    UnexpectedCharacter { position: usize, found: char },
+    /// Command at byte `position` is missing some of its arguments.
+    MissingArguments {
+        position: usize,
+        command: crate::path::Command,
+    },
+    /// Malformed number or arc flag at byte `position`.
+    InvalidNumber(usize),
+}
+
+#[cfg(feature = "path")]
+impl Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathError::MissingInitialMove => f.write_str("path data must begin with a move command"),
+            PathError::CloseBeforeDraw(index) => {
+                write!(f, "close command (segment {}) precedes any drawing command", index)
+            }
+            PathError::OrphanSmooth(index) => write!(
+                f,
+                "smooth command (segment {}) doesn't follow a curve of the same type",
+                index
+            ),
+            PathError::UnexpectedCharacter { position, found } => {
+                write!(f, "unexpected character '{}' at {}", found, position)
+            }
+            PathError::MissingArguments { position, command } => write!(
+                f,
+                "'{}' command at {} expects {} arguments",
+                command.absolute(),
+                position,
+                command.argument_count()
+            ),
+            PathError::InvalidNumber(position) => write!(f, "invalid number at {}", position),
+        }
+    }
+}
+
+#[cfg(feature = "path")]
+impl std::error::Error for PathError {}
+
+#[cfg(feature = "path")]
+impl PathError {
+    /// Returns `true` if this error was caused by path data that doesn't
+    /// follow the path data grammar, and `false` if it's a result of
+    /// [linting](crate::path::PathData::lint).
+    pub fn is_syntax_error(&self) -> bool {
+        matches!(
+            self,
+            PathError::MissingInitialMove
+                | PathError::UnexpectedCharacter { .. }
+                | PathError::MissingArguments { .. }
+                | PathError::InvalidNumber(_)
+        )
+    }
+}
+
+#[cfg(feature = "path")]
+impl From<PathError> for Error {
+    fn from(value: PathError) -> Self {
+        if value.is_syntax_error() {
+            Error::Parse(ParseError::Path(value))
+        } else {
+            Error::Validation(ValidationError::Path(value))
+        }
+    }
+}
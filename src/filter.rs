@@ -0,0 +1,227 @@
+//! Filter effects applied to rendered elements.
+//!
+//! Only a few filter primitives are supported for now, see
+//! [`FilterPrimitive`].
+
+use std::borrow::Cow;
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    common::{CoreAttributes, PositionSize, Units},
+    math::{Number, PositiveNumber},
+};
+
+/// Defines a filter effect composed of filter primitives, which is applied to
+/// elements referencing it through the `filter` property.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementFilter<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Coordinate system for `x`, `y`, `width` and `height` attributes.
+    ///
+    /// Treated as [`Units::ObjectBoundingBox`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterElementFilterUnitsAttribute)
+    #[xml_attribute {
+        name: "filterUnits",
+    }]
+    pub filter_units: Option<Units>,
+
+    /// Coordinate system for attributes of filter primitives.
+    ///
+    /// Treated as [`Units::UserSpaceOnUse`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterElementPrimitiveUnitsAttribute)
+    #[xml_attribute {
+        name: "primitiveUnits",
+    }]
+    pub primitive_units: Option<Units>,
+
+    /// Filter effects region, defaulting to `-10%`, `-10%`, `120%` and `120%`
+    /// respectively.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterEffectsRegion)
+    #[xml_attribute_bundle]
+    pub position_size: Box<PositionSize>,
+
+    /// Filter primitives applied in order.
+    pub children: Vec<FilterPrimitive<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementFilter<'_> {
+    fn size_hint(&self) -> usize {
+        "<filter></filter>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<filter")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "filter", settings)
+    }
+}
+
+/// Any element that can be a child of a `<filter>` element.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum FilterPrimitive<'a> {
+    GaussianBlur(ElementFeGaussianBlur<'a>),
+    Offset(ElementFeOffset<'a>),
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for FilterPrimitive<'_> {
+    fn size_hint(&self) -> usize {
+        match self {
+            FilterPrimitive::GaussianBlur(it) => it.size_hint(),
+            FilterPrimitive::Offset(it) => it.size_hint(),
+        }
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            FilterPrimitive::GaussianBlur(it) => it.write_to(writer, settings),
+            FilterPrimitive::Offset(it) => it.write_to(writer, settings),
+        }
+    }
+}
+
+/// Attributes shared by all filter primitives.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#CommonAttributes)
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FilterPrimitiveAttributes<'a> {
+    /// Subregion the primitive is restricted to, defaulting to the filter
+    /// effects region.
+    #[xml_attribute_bundle]
+    pub position_size: PositionSize,
+
+    /// Name under which the output of the primitive can be referenced by
+    /// subsequent primitives in the same filter.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterPrimitiveResultAttribute)
+    #[xml_attribute]
+    pub result: Option<Cow<'a, str>>,
+}
+
+/// Blurs the input image.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#feGaussianBlurElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementFeGaussianBlur<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Subregion and result name.
+    #[xml_attribute_bundle]
+    pub primitive: Box<FilterPrimitiveAttributes<'a>>,
+
+    /// Input of the primitive, such as `SourceGraphic` or a result of a
+    /// preceding primitive.
+    ///
+    /// Output of the preceding primitive is used when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterPrimitiveInAttribute)
+    #[xml_attribute {
+        name: "in",
+    }]
+    pub input: Option<Cow<'a, str>>,
+
+    /// Standard deviation of the blur, in both directions.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#feGaussianBlurStdDeviationAttribute)
+    #[xml_attribute {
+        name: "stdDeviation",
+    }]
+    pub std_deviation: Option<PositiveNumber>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementFeGaussianBlur<'_> {
+    fn size_hint(&self) -> usize {
+        "<feGaussianBlur/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<feGaussianBlur")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "feGaussianBlur", settings)
+    }
+}
+
+/// Offsets the input image.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#feOffsetElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementFeOffset<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Subregion and result name.
+    #[xml_attribute_bundle]
+    pub primitive: Box<FilterPrimitiveAttributes<'a>>,
+
+    /// Input of the primitive, such as `SourceGraphic` or a result of a
+    /// preceding primitive.
+    ///
+    /// Output of the preceding primitive is used when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterPrimitiveInAttribute)
+    #[xml_attribute {
+        name: "in",
+    }]
+    pub input: Option<Cow<'a, str>>,
+
+    /// Horizontal offset, in the coordinate system of `primitiveUnits`.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#feOffsetDxAttribute)
+    #[xml_attribute]
+    pub dx: Option<Number>,
+
+    /// Vertical offset, in the coordinate system of `primitiveUnits`.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#feOffsetDyAttribute)
+    #[xml_attribute]
+    pub dy: Option<Number>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementFeOffset<'_> {
+    fn size_hint(&self) -> usize {
+        "<feOffset/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<feOffset")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "feOffset", settings)
+    }
+}
@@ -1,20 +1,615 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, rc::Rc, sync::Arc};
+
+/// Number of decimal places numbers are written with by default.
+pub const DEFAULT_PRECISION: usize = 4;
+
+/// Markup language rules followed when writing a document.
+///
+/// Writing HTML differs from writing XML in following ways:
+/// - elements without content are closed with an end tag instead of being
+///   self-closed (`<path></path>` instead of `<path/>`), as the HTML fragment
+///   serialization algorithm does for non-void elements, regardless of
+///   [`WriteSettings::self_close_empty`],
+/// - `xml:lang` is written as `lang`,
+/// - `<script>` and `<style>` content isn't wrapped in a CDATA section.
+///
+/// `xml:space` has no HTML equivalent so it's written as is.
+#[cfg(feature = "write")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    /// Standalone SVG documents, or SVG embedded in XHTML.
+    #[default]
+    Xml,
+    /// SVG embedded in HTML documents.
+    Html,
+}
+
+/// Formatting of numbers in written values.
+///
+/// Numbers are always written with `.` as the decimal separator and without
+/// digit grouping, as required by SVG, regardless of the system locale.
+#[cfg(feature = "write")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Whether the zero before the decimal separator of numbers between `-1`
+    /// and `1` is written (`0.5`) or omitted (`.5`).
+    ///
+    /// SVG allows both forms, but some tools don't accept the shorter one.
+    pub leading_zero: bool,
+}
+
+#[cfg(feature = "write")]
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat { leading_zero: true }
+    }
+}
+
+/// Spelling of boolean attribute values, see
+/// [`XmlBool`](crate::common::XmlBool).
+#[cfg(feature = "write")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BoolStyle {
+    /// `true` and `false`.
+    #[default]
+    Keyword,
+    /// `1` and `0`.
+    Numeric,
+}
 
 #[cfg(feature = "write")]
 #[derive(Debug, Clone)]
 pub struct WriteSettings {
     pub precision: usize,
+    pub dialect: Dialect,
+    /// Number of spaces child elements are indented by, one level per
+    /// nesting depth.
+    ///
+    /// Children are written on the same line as their parent when `None`.
+    pub indent: Option<usize>,
+    /// Nesting depth of the element that's currently being written.
+    ///
+    /// Incremented by container elements while writing their children.
+    pub depth: usize,
+    /// Whether the root `<svg>` element is preceded by an XML declaration
+    /// (`<?xml version="1.0" encoding="UTF-8"?>`).
+    pub xml_declaration: bool,
+    /// Value of `standalone` document declaration written as part of the XML
+    /// declaration.
+    ///
+    /// Only used when `xml_declaration` is enabled.
+    pub standalone: Option<bool>,
+    /// Document type declaration written before the root `<svg>` element, as
+    /// `<!DOCTYPE {doctype}>`.
+    ///
+    /// See [`SVG_11_DOCTYPE`](crate::svg::SVG_11_DOCTYPE).
+    pub doctype: Option<Cow<'static, str>>,
+    /// Whether element attributes are written in lexicographic order of their
+    /// names, instead of the order in which they're declared.
+    ///
+    /// Useful for reproducible output, at the cost of buffering attributes
+    /// of each element.
+    pub sort_attributes: bool,
+    /// Spelling of boolean attribute values.
+    pub bool_style: BoolStyle,
+    /// Maximum width of lines in long list values, such as path data and
+    /// point lists, measured from the start of the value.
+    ///
+    /// Values are only broken between list items and continuation lines are
+    /// indented one level deeper than the element. Wrapping is purely
+    /// cosmetic, wrapped values parse the same as unwrapped ones.
+    pub max_line_width: Option<usize>,
+    /// Whether elements without content are self-closed (`<path/>`) instead
+    /// of being closed with an end tag (`<path></path>`).
+    ///
+    /// Applies to leaf elements as well as to containers without children.
+    /// Ignored by the [HTML dialect](Dialect::Html), which always writes end
+    /// tags as no SVG element is an HTML void element.
+    pub self_close_empty: bool,
+    /// Quote character attribute values are enclosed in, either `"` or `'`.
+    ///
+    /// Occurrences of the active quote character in attribute values are
+    /// escaped. Single quotes are useful when the output is embedded in a
+    /// double-quoted attribute of another document.
+    pub attribute_quote: char,
+    /// Formatting of written numbers.
+    pub number_format: NumberFormat,
 }
 
 #[cfg(feature = "write")]
 impl Default for WriteSettings {
     fn default() -> Self {
-        WriteSettings { precision: 4 }
+        WriteSettings {
+            precision: DEFAULT_PRECISION,
+            dialect: Dialect::Xml,
+            indent: None,
+            depth: 0,
+            xml_declaration: false,
+            standalone: None,
+            doctype: None,
+            sort_attributes: false,
+            bool_style: BoolStyle::Keyword,
+            max_line_width: None,
+            self_close_empty: true,
+            attribute_quote: '"',
+            number_format: NumberFormat::default(),
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl WriteSettings {
+    /// Returns a builder starting from [default](WriteSettings::default)
+    /// settings.
+    ///
+    /// ```
+    /// use structuredvg::io::{Dialect, WriteSettings};
+    ///
+    /// let settings = WriteSettings::builder()
+    ///     .precision(2)
+    ///     .indent(2)
+    ///     .dialect(Dialect::Html)
+    ///     .sort_attributes(true)
+    ///     .build();
+    ///
+    /// assert_eq!(settings.precision, 2);
+    /// assert_eq!(settings.indent, Some(2));
+    /// assert!(settings.self_close_empty);
+    /// ```
+    #[inline]
+    pub fn builder() -> WriteSettingsBuilder {
+        WriteSettingsBuilder::default()
+    }
+
+    /// Returns settings used for writing children of the current element.
+    #[inline]
+    pub fn nested(&self) -> WriteSettings {
+        WriteSettings {
+            depth: self.depth + 1,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy of these settings that writes numbers with `precision`
+    /// decimal places.
+    ///
+    /// This is the preferred way of writing a single value with different
+    /// precision, as the original settings are left untouched:
+    ///
+    /// ```ignore
+    /// path.write_to(&mut writer, &settings.with_precision(6))?;
+    /// ```
+    #[inline]
+    pub fn with_precision(&self, precision: usize) -> WriteSettings {
+        WriteSettings {
+            precision,
+            ..self.clone()
+        }
+    }
+
+    /// Writes the XML declaration and the document type declaration, if
+    /// they're enabled.
+    ///
+    /// Called by root elements before they write their own tag.
+    pub fn write_prolog<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        if self.xml_declaration {
+            writer.write_all(br#"<?xml version="1.0" encoding="UTF-8""#)?;
+            match self.standalone {
+                Some(true) => writer.write_all(br#" standalone="yes""#)?,
+                Some(false) => writer.write_all(br#" standalone="no""#)?,
+                None => {}
+            }
+            writer.write_all(b"?>\n")?;
+        }
+        if let Some(doctype) = &self.doctype {
+            writer.write_all(b"<!DOCTYPE ")?;
+            writer.write_all(doctype.as_bytes())?;
+            writer.write_all(b">\n")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the [`attribute_quote`](WriteSettings::attribute_quote)
+    /// character.
+    #[inline]
+    pub fn write_attribute_quote<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&[self.attribute_quote as u8])
+    }
+
+    /// Returns a helper writing line breaks and indentation for these
+    /// settings.
+    #[inline]
+    pub fn indenter(&self) -> Indenter {
+        Indenter {
+            indent: self.indent,
+        }
+    }
+
+    /// Starts a new line indented to current `depth` if `indent` is set.
+    pub fn write_line_start<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let indenter = self.indenter();
+        indenter.write_newline(writer)?;
+        indenter.write_indent(writer, self.depth)
+    }
+}
+
+/// Writes line breaks and indentation of pretty-printed output, see
+/// [`WriteSettings::indent`].
+///
+/// All methods are no-ops when indentation is disabled, so elements can call
+/// them unconditionally.
+#[cfg(feature = "write")]
+#[derive(Debug, Clone, Copy)]
+pub struct Indenter {
+    indent: Option<usize>,
+}
+
+#[cfg(feature = "write")]
+impl Indenter {
+    /// Returns `true` if output is pretty-printed.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.indent.is_some()
+    }
+
+    /// Writes indentation of an element nested `depth` levels deep.
+    pub fn write_indent<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        depth: usize,
+    ) -> std::io::Result<()> {
+        if let Some(indent) = self.indent {
+            for _ in 0..indent * depth {
+                writer.write_all(b" ")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a line break.
+    #[inline]
+    pub fn write_newline<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        match self.indent {
+            Some(_) => writer.write_all(b"\n"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Builder for [`WriteSettings`], see [`WriteSettings::builder`].
+///
+/// Settings that aren't set keep their default values.
+#[cfg(feature = "write")]
+#[derive(Debug, Clone, Default)]
+pub struct WriteSettingsBuilder {
+    settings: WriteSettings,
+}
+
+#[cfg(feature = "write")]
+impl WriteSettingsBuilder {
+    /// Sets [`WriteSettings::precision`].
+    #[inline]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.settings.precision = precision;
+        self
+    }
+
+    /// Sets [`WriteSettings::dialect`].
+    #[inline]
+    pub fn dialect(mut self, dialect: Dialect) -> Self {
+        self.settings.dialect = dialect;
+        self
+    }
+
+    /// Indents child elements by `spaces`, see [`WriteSettings::indent`].
+    #[inline]
+    pub fn indent(mut self, spaces: usize) -> Self {
+        self.settings.indent = Some(spaces);
+        self
+    }
+
+    /// Sets [`WriteSettings::xml_declaration`].
+    #[inline]
+    pub fn xml_declaration(mut self, xml_declaration: bool) -> Self {
+        self.settings.xml_declaration = xml_declaration;
+        self
+    }
+
+    /// Sets [`WriteSettings::standalone`].
+    #[inline]
+    pub fn standalone(mut self, standalone: bool) -> Self {
+        self.settings.standalone = Some(standalone);
+        self
+    }
+
+    /// Sets [`WriteSettings::doctype`].
+    #[inline]
+    pub fn doctype(mut self, doctype: impl Into<Cow<'static, str>>) -> Self {
+        self.settings.doctype = Some(doctype.into());
+        self
+    }
+
+    /// Sets [`WriteSettings::sort_attributes`].
+    #[inline]
+    pub fn sort_attributes(mut self, sort_attributes: bool) -> Self {
+        self.settings.sort_attributes = sort_attributes;
+        self
+    }
+
+    /// Sets [`WriteSettings::bool_style`].
+    #[inline]
+    pub fn bool_style(mut self, bool_style: BoolStyle) -> Self {
+        self.settings.bool_style = bool_style;
+        self
+    }
+
+    /// Sets [`WriteSettings::max_line_width`].
+    #[inline]
+    pub fn max_line_width(mut self, max_line_width: usize) -> Self {
+        self.settings.max_line_width = Some(max_line_width);
+        self
+    }
+
+    /// Sets [`WriteSettings::self_close_empty`].
+    #[inline]
+    pub fn self_close_empty(mut self, self_close_empty: bool) -> Self {
+        self.settings.self_close_empty = self_close_empty;
+        self
+    }
+
+    /// Sets [`WriteSettings::attribute_quote`].
+    #[inline]
+    pub fn attribute_quote(mut self, attribute_quote: char) -> Self {
+        debug_assert!(
+            attribute_quote == '"' || attribute_quote == '\'',
+            "attribute values can only be quoted with '\"' or '\''"
+        );
+        self.settings.attribute_quote = attribute_quote;
+        self
+    }
+
+    /// Sets [`WriteSettings::number_format`].
+    #[inline]
+    pub fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.settings.number_format = number_format;
+        self
+    }
+
+    #[inline]
+    pub fn build(self) -> WriteSettings {
+        self.settings
+    }
+}
+
+/// Writes `items` of a list value separated by `separator`, wrapping lines
+/// that would exceed [`max_line_width`](WriteSettings::max_line_width).
+///
+/// Items longer than the limit are placed on a line of their own.
+#[cfg(feature = "write")]
+pub fn write_wrapped<'i, W: std::io::Write, T: Writable + 'i>(
+    items: impl IntoIterator<Item = &'i T>,
+    separator: &[u8],
+    writer: &mut W,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    let Some(max_width) = settings.max_line_width else {
+        for (i, item) in items.into_iter().enumerate() {
+            if i > 0 {
+                writer.write_all(separator)?;
+            }
+            item.write_to(writer, settings)?;
+        }
+        return Ok(());
+    };
+
+    let indenter = settings.indenter();
+    let continuation_indent = settings.indent.unwrap_or_default() * (settings.depth + 1);
+    let mut buffer = Vec::new();
+    let mut line_width = 0;
+    for (i, item) in items.into_iter().enumerate() {
+        buffer.clear();
+        item.write_to(&mut buffer, settings)?;
+
+        if i > 0 {
+            if line_width + separator.len() + buffer.len() > max_width {
+                // values are broken even if output isn't pretty-printed
+                writer.write_all(b"\n")?;
+                indenter.write_indent(writer, settings.depth + 1)?;
+                line_width = continuation_indent;
+            } else {
+                writer.write_all(separator)?;
+                line_width += separator.len();
+            }
+        }
+        writer.write_all(&buffer)?;
+        line_width += buffer.len();
+    }
+    Ok(())
+}
+
+/// Returns the sum of [size hints](Writable::size_hint) of `children` of a
+/// container element.
+#[cfg(feature = "write")]
+pub fn children_size_hint<C: Writable>(children: &[C]) -> usize {
+    children.iter().map(Writable::size_hint).sum()
+}
+
+/// Writes attributes of an element, sorting them by name if
+/// [`sort_attributes`](WriteSettings::sort_attributes) is enabled.
+///
+/// Attributes are separated from the preceding tag name by a space, which
+/// isn't written if the element has no attributes.
+///
+/// Elements should use this function instead of calling
+/// [`AttributeBundle::write_attributes`] on themselves directly.
+#[cfg(feature = "write")]
+pub fn write_element_attributes<W: std::io::Write, B: AttributeBundle + ?Sized>(
+    element: &B,
+    writer: &mut W,
+    settings: &WriteSettings,
+) -> std::io::Result<bool> {
+    if !settings.sort_attributes {
+        return element.write_attributes(&mut SeparatedWriter::new(writer, true), settings);
+    }
+
+    let mut pairs = Vec::new();
+    element.write_attribute_pairs(&mut pairs, settings)?;
+    // stable sort keeps repeated attributes in their declared order
+    pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (name, value) in &pairs {
+        writer.write_all(b" ")?;
+        writer.write_all(name)?;
+        writer.write_all(b"=")?;
+        settings.write_attribute_quote(writer)?;
+        writer.write_all(value)?;
+        settings.write_attribute_quote(writer)?;
+    }
+    Ok(!pairs.is_empty())
+}
+
+/// Name of an attribute and its value as written, without quotes.
+///
+/// Produced by [`AttributeBundle::write_attribute_pairs`] so attributes can
+/// be [sorted](WriteSettings::sort_attributes) before they're written.
+#[cfg(feature = "write")]
+pub type AttributePair = (Cow<'static, [u8]>, Vec<u8>);
+
+/// Writer that writes a space before the first written bytes if a separator
+/// is `pending`.
+///
+/// Used by derived [`AttributeBundle`] implementations so nested bundles are
+/// only separated from preceding attributes if they write any attributes.
+#[cfg(feature = "write")]
+pub struct SeparatedWriter<'w, W: std::io::Write> {
+    inner: &'w mut W,
+    pending: bool,
+}
+
+#[cfg(feature = "write")]
+impl<'w, W: std::io::Write> SeparatedWriter<'w, W> {
+    #[inline]
+    pub fn new(inner: &'w mut W, pending: bool) -> Self {
+        SeparatedWriter { inner, pending }
+    }
+}
+
+#[cfg(feature = "write")]
+impl<W: std::io::Write> std::io::Write for SeparatedWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.pending && !buf.is_empty() {
+            self.inner.write_all(b" ")?;
+            self.pending = false;
+        }
+        self.inner.write(buf)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Ends an element that has no content, see [`Dialect`] and
+/// [`WriteSettings::self_close_empty`].
+#[cfg(feature = "write")]
+pub fn write_empty_element_end<W: std::io::Write>(
+    writer: &mut W,
+    tag: &str,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    if settings.dialect == Dialect::Xml && settings.self_close_empty {
+        return writer.write_all(b"/>");
+    }
+    writer.write_all(b"></")?;
+    writer.write_all(tag.as_bytes())?;
+    writer.write_all(b">")
+}
+
+/// Writes `children` of a container element and closes it.
+///
+/// Containers without children are ended like leaf elements, see
+/// [`write_empty_element_end`].
+#[cfg(feature = "write")]
+pub fn write_element_content<W: std::io::Write, C: Writable>(
+    children: &[C],
+    writer: &mut W,
+    tag: &str,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    if children.is_empty() {
+        return write_empty_element_end(writer, tag, settings);
+    }
+    writer.write_all(b">")?;
+    write_children(children, writer, settings)?;
+    writer.write_all(b"</")?;
+    writer.write_all(tag.as_bytes())?;
+    writer.write_all(b">")
+}
+
+/// Writes `children` of a container element.
+///
+/// Each child is placed on its own line, one level deeper than the container,
+/// if `settings` have indentation enabled.
+#[cfg(feature = "write")]
+pub fn write_children<W: std::io::Write, C: Writable>(
+    children: &[C],
+    writer: &mut W,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    if children.is_empty() {
+        return Ok(());
+    }
+
+    let nested = settings.nested();
+    for child in children {
+        nested.write_line_start(writer)?;
+        child.write_to(writer, &nested)?;
+    }
+    settings.write_line_start(writer)
+}
+
+/// Writes text content of `<script>` and `<style>` elements.
+///
+/// Content is wrapped in a CDATA section when writing XML, so it doesn't need
+/// to be escaped. HTML parsers treat content of these elements as raw text, so
+/// it's written as is.
+#[cfg(feature = "write")]
+pub fn write_raw_text<W: std::io::Write>(
+    writer: &mut W,
+    content: &str,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    if content.is_empty() {
+        return Ok(());
+    }
+
+    match settings.dialect {
+        Dialect::Xml => {
+            writer.write_all(b"<![CDATA[")?;
+            // CDATA section can't contain its terminator so it's split in
+            // between `]]` and `>`
+            let mut parts = content.split("]]>");
+            if let Some(first) = parts.next() {
+                writer.write_all(first.as_bytes())?;
+            }
+            for part in parts {
+                writer.write_all(b"]]]]><![CDATA[>")?;
+                writer.write_all(part.as_bytes())?;
+            }
+            writer.write_all(b"]]>")
+        }
+        Dialect::Html => writer.write_all(content.as_bytes()),
     }
 }
 
 /// Unifies writing behavior between different types so their implementations
 /// are easier to generate with the macro.
+///
+/// Types that can also be parsed with [`FromStr`](std::str::FromStr) must
+/// write values that parse back into an equal value, up to the rounding of
+/// numbers to [`precision`](WriteSettings::precision). Writing a parsed value
+/// is therefore idempotent: parsing written output and writing it again with
+/// the same settings produces the same output.
 #[cfg(feature = "write")]
 pub trait Writable {
     /// Writes this value to a writer.
@@ -27,33 +622,142 @@ pub trait Writable {
         settings: &WriteSettings,
     ) -> std::io::Result<()>;
 
+    /// Returns a rough estimate of the number of bytes this value writes.
+    ///
+    /// The hint is advisory and only used to pre-allocate buffers, it's not
+    /// guaranteed to be exact nor to be an upper or lower bound.
+    #[inline]
+    fn size_hint(&self) -> usize {
+        0
+    }
+
+    /// Writes this value to a writer and returns the number of bytes that
+    /// were written.
+    fn write_to_counted<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<usize> {
+        let mut writer = CountingWriter::new(writer);
+        self.write_to(&mut writer, settings)?;
+        Ok(writer.count())
+    }
+
+    /// Writes this value to a writer with default settings and numbers
+    /// written with `precision` decimal places.
+    ///
+    /// Use [`WriteSettings::with_precision`] to override precision of other
+    /// settings.
+    fn write_to_precise<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        precision: usize,
+    ) -> std::io::Result<()> {
+        self.write_to(writer, &WriteSettings::default().with_precision(precision))
+    }
+
+    /// Returns the string this value is backed by, if any, see
+    /// [`AttributeValue::as_str`].
+    ///
+    /// Attribute bundles write such values through [`WriteEscaped`] instead of
+    /// calling [`write_to`](Writable::write_to).
+    #[inline]
+    fn as_attribute_str(&self) -> Option<&str> {
+        None
+    }
+
+    /// Writes this value into a new buffer pre-allocated from
+    /// [`size_hint`](Writable::size_hint) and returns it.
+    fn write_to_vec(&self, settings: &WriteSettings) -> Vec<u8> {
+        let mut buffer = Vec::with_capacity(self.size_hint());
+        self.write_to(&mut buffer, settings)
+            .expect("unable to write to byte buffer");
+        buffer
+    }
+
     fn write_to_string(&self, settings: &WriteSettings) -> String {
-        let mut cursor = std::io::Cursor::new(Vec::new());
-        self.write_to(&mut cursor, settings)
-            .expect("unable to write to string buffer");
         unsafe {
             // SAFETY: write_to must only output valid UTF-8
-            std::str::from_utf8_unchecked(cursor.into_inner().as_slice()).to_string()
+            String::from_utf8_unchecked(self.write_to_vec(settings))
         }
     }
 }
 
+/// Writer adapter that keeps track of the number of bytes written through it.
+#[cfg(feature = "write")]
+#[derive(Debug)]
+pub struct CountingWriter<W: std::io::Write> {
+    inner: W,
+    count: usize,
+}
+
+#[cfg(feature = "write")]
+impl<W: std::io::Write> CountingWriter<W> {
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        CountingWriter { inner, count: 0 }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(feature = "write")]
+impl<W: std::io::Write> std::io::Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written;
+        Ok(written)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
 /// Implementation of `From<String>` which is only called when a provided
 /// `String` is known to be valid representation of constructed struct.
 ///
 /// [`FromStr`](std::str::FromStr) should be used when validity of the passed
 /// string isn't known.
 pub trait FromStringUnsafe {
+    /// Constructs the value from its string representation.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be a valid representation of `Self`. Implementations are
+    /// allowed to skip validation and produce an invalid value otherwise.
     unsafe fn from(value: String) -> Self;
 }
 
-impl<F: From<String>> FromStringUnsafe for F {
+impl FromStringUnsafe for String {
+    #[inline]
+    unsafe fn from(value: String) -> Self {
+        value
+    }
+}
+
+impl FromStringUnsafe for Cow<'_, str> {
+    #[inline]
     unsafe fn from(value: String) -> Self {
-        From::from(value)
+        Cow::Owned(value)
     }
 }
 
 /// Type is a valid SVG value.
+///
+/// Values aren't [`Writable`] through a blanket implementation, as that would
+/// conflict with the implementation for references. Implementors should
+/// implement [`Writable`] by delegating to [`AttributeValue::write_to`].
 pub trait AttributeValue: ToString + FromStringUnsafe {
     #[cfg(feature = "write")]
     fn write_to<W: std::io::Write>(
@@ -68,19 +772,150 @@ pub trait AttributeValue: ToString + FromStringUnsafe {
     /// the value.
     #[inline]
     fn as_str(&self) -> Option<&str> {
-        return None;
+        None
     }
 }
 
+/// Type can be read from the textual value of an attribute.
+///
+/// Used by [`AttributeBundle::read_attribute`] implementations generated for
+/// `#[xml_attribute]` fields. `None` is returned if `value` isn't a valid
+/// representation of the type.
+#[cfg(feature = "read")]
+pub trait Readable: Sized {
+    fn read(value: &str) -> Option<Self>;
+}
+
+#[cfg(feature = "read")]
+impl Readable for String {
+    #[inline]
+    fn read(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+#[cfg(feature = "read")]
+impl Readable for Cow<'_, str> {
+    #[inline]
+    fn read(value: &str) -> Option<Self> {
+        Some(Cow::Owned(value.to_string()))
+    }
+}
+
+/// Writes `value` with [`precision`](WriteSettings::precision) decimal places
+/// and [`number_format`](WriteSettings::number_format) of `settings`.
+#[cfg(feature = "write")]
+pub fn write_number<W: std::io::Write>(
+    writer: &mut W,
+    value: crate::math::Number,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    if settings.number_format.leading_zero {
+        return write!(writer, "{:.prec$}", value, prec = settings.precision);
+    }
+
+    let formatted = format!("{:.prec$}", value, prec = settings.precision);
+    let (sign, digits) = match formatted.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", formatted.as_str()),
+    };
+    let digits = match digits.strip_prefix('0') {
+        Some(fraction) if fraction.starts_with('.') => fraction,
+        _ => digits,
+    };
+    writer.write_all(sign.as_bytes())?;
+    writer.write_all(digits.as_bytes())
+}
+
+/// Writer adapter that replaces characters which can't appear in a quoted
+/// attribute value (`&`, `<` and the quote character) or character data
+/// (`&`, `<` and `>`) with entity references.
+#[cfg(feature = "write")]
+#[derive(Debug)]
+pub struct WriteEscaped<'a, W: std::io::Write> {
+    inner: &'a mut W,
+    /// Quote character of the attribute value, or `0` for character data.
+    quote: u8,
+}
+
+#[cfg(feature = "write")]
+impl<'a, W: std::io::Write> WriteEscaped<'a, W> {
+    /// Escapes output for a double-quoted attribute value.
+    #[inline]
+    pub fn new(inner: &'a mut W) -> Self {
+        WriteEscaped { inner, quote: b'"' }
+    }
+
+    /// Escapes output for an attribute value quoted with
+    /// [`attribute_quote`](WriteSettings::attribute_quote) of `settings`.
+    #[inline]
+    pub fn for_settings(inner: &'a mut W, settings: &WriteSettings) -> Self {
+        WriteEscaped {
+            inner,
+            quote: settings.attribute_quote as u8,
+        }
+    }
+
+    /// Escapes output for character data of an element.
+    ///
+    /// `>` is escaped as well, so content can't end a CDATA section.
+    #[inline]
+    pub fn for_text(inner: &'a mut W) -> Self {
+        WriteEscaped { inner, quote: 0 }
+    }
+}
+
+#[cfg(feature = "write")]
+impl<W: std::io::Write> std::io::Write for WriteEscaped<'_, W> {
+    /// Writes all of `buf`, escaped, as escaped output can't be split at
+    /// arbitrary positions.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut start = 0;
+        for (i, byte) in buf.iter().enumerate() {
+            let escaped: &[u8] = match *byte {
+                b'&' => b"&amp;",
+                b'<' => b"&lt;",
+                b'"' if self.quote == b'"' => b"&quot;",
+                b'\'' if self.quote == b'\'' => b"&apos;",
+                b'>' if self.quote == 0 => b"&gt;",
+                _ => continue,
+            };
+            self.inner.write_all(&buf[start..i])?;
+            self.inner.write_all(escaped)?;
+            start = i + 1;
+        }
+        self.inner.write_all(&buf[start..])?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Writes `value` with characters that can't appear in an attribute value
+/// quoted with [`attribute_quote`](WriteSettings::attribute_quote) (`&`, `<`
+/// and the quote character) replaced by entity references.
+#[cfg(feature = "write")]
+#[inline]
+pub fn write_str_escaped<W: std::io::Write>(
+    writer: &mut W,
+    value: &str,
+    settings: &WriteSettings,
+) -> std::io::Result<()> {
+    let mut escaped = WriteEscaped::for_settings(writer, settings);
+    std::io::Write::write_all(&mut escaped, value.as_bytes())
+}
+
 impl AttributeValue for Cow<'_, str> {
     #[cfg(feature = "write")]
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &WriteSettings,
+        settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        writer.write(self.as_bytes())?;
-        Ok(())
+        write_str_escaped(writer, self, settings)
     }
 
     fn as_str(&self) -> Option<&str> {
@@ -88,26 +923,99 @@ impl AttributeValue for Cow<'_, str> {
     }
 }
 
+impl_writable_attribute_value!(Cow<'_, str>);
+
 impl AttributeValue for String {
     #[cfg(feature = "write")]
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &WriteSettings,
+        settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        writer.write(self.as_bytes())?;
-        Ok(())
+        write_str_escaped(writer, self, settings)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        Some(self.as_str())
+    }
+}
+
+impl_writable_attribute_value!(String);
+
+macro_rules! impl_integer_value {
+    [$($integer: ty),*] => {$(
+        impl FromStringUnsafe for $integer {
+            unsafe fn from(value: String) -> Self {
+                value.trim().parse().unwrap_or_default()
+            }
+        }
+
+        #[cfg(feature = "read")]
+        impl Readable for $integer {
+            #[inline]
+            fn read(value: &str) -> Option<Self> {
+                value.trim().parse().ok()
+            }
+        }
+
+        impl AttributeValue for $integer {
+            #[cfg(feature = "write")]
+            fn write_to<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+                _settings: &WriteSettings,
+            ) -> std::io::Result<()> {
+                write!(writer, "{}", self)
+            }
+        }
+
+        impl_writable_attribute_value!($integer);
+    )*};
+}
+
+impl_integer_value![i32, i64, isize, u32, u64, usize];
+
+#[cfg(feature = "write")]
+impl<T: Writable + ?Sized> Writable for &T {
+    #[inline]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        (**self).write_to(writer, settings)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        (**self).size_hint()
+    }
+
+    #[inline]
+    fn as_attribute_str(&self) -> Option<&str> {
+        (**self).as_attribute_str()
     }
 }
 
 #[cfg(feature = "write")]
-impl<V: AttributeValue> Writable for V {
+impl<T: Writable + ToOwned + ?Sized> Writable for Cow<'_, T> {
+    #[inline]
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
         settings: &WriteSettings,
     ) -> std::io::Result<()> {
-        AttributeValue::write_to(self, writer, settings)
+        self.as_ref().write_to(writer, settings)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_ref().size_hint()
+    }
+
+    #[inline]
+    fn as_attribute_str(&self) -> Option<&str> {
+        self.as_ref().as_attribute_str()
     }
 }
 
@@ -124,6 +1032,21 @@ pub trait Attribute<'a> {
         settings: &WriteSettings,
     ) -> std::io::Result<()>;
 
+    /// Returns the name of this attribute and its value as
+    /// [written](Attribute::write_attribute), without quotes.
+    #[cfg(feature = "write")]
+    fn write_attribute_pair(&self, settings: &WriteSettings) -> std::io::Result<AttributePair>;
+
+    /// Constructs the attribute from a `name` and `value` pair read from a
+    /// document, or returns `None` if `name` doesn't belong to this attribute.
+    #[cfg(feature = "read")]
+    fn read_attribute(_name: &str, _value: &str) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     /// Returns the name of the attribute.
     fn name(&'a self) -> &'a str;
 
@@ -151,6 +1074,27 @@ pub trait AttributeBundle {
         writer: &mut W,
         settings: &WriteSettings,
     ) -> std::io::Result<bool>;
+
+    /// Appends a `(name, value)` pair for every attribute
+    /// [`write_attributes`](AttributeBundle::write_attributes) would write, in
+    /// the same order.
+    #[cfg(feature = "write")]
+    fn write_attribute_pairs(
+        &self,
+        pairs: &mut Vec<AttributePair>,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()>;
+
+    /// Stores attribute `name` with `value` read from a document.
+    ///
+    /// Returns `false` if no attribute of this bundle is called `name`, and
+    /// an error if the attribute is recognized but `value` isn't valid.
+    #[cfg(feature = "read")]
+    fn read_attribute(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<bool, crate::error::InvalidAttributeValue>;
 }
 
 impl<'a, A: Attribute<'a>> AttributeBundle for A {
@@ -163,6 +1107,31 @@ impl<'a, A: Attribute<'a>> AttributeBundle for A {
         self.write_attribute(writer, settings)?;
         Ok(true)
     }
+
+    #[cfg(feature = "write")]
+    fn write_attribute_pairs(
+        &self,
+        pairs: &mut Vec<AttributePair>,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        pairs.push(self.write_attribute_pair(settings)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "read")]
+    fn read_attribute(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<bool, crate::error::InvalidAttributeValue> {
+        match A::read_attribute(name, value) {
+            Some(it) => {
+                *self = it;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 impl<'a, A: Attribute<'a>> AttributeBundle for Option<A> {
@@ -180,6 +1149,33 @@ impl<'a, A: Attribute<'a>> AttributeBundle for Option<A> {
             None => Ok(false),
         }
     }
+
+    #[cfg(feature = "write")]
+    fn write_attribute_pairs(
+        &self,
+        pairs: &mut Vec<AttributePair>,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        if let Some(it) = self {
+            pairs.push(it.write_attribute_pair(settings)?);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "read")]
+    fn read_attribute(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<bool, crate::error::InvalidAttributeValue> {
+        match A::read_attribute(name, value) {
+            Some(it) => {
+                *self = Some(it);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
 }
 
 impl<'a, A: Attribute<'a>> AttributeBundle for Vec<A> {
@@ -189,11 +1185,80 @@ impl<'a, A: Attribute<'a>> AttributeBundle for Vec<A> {
         writer: &mut W,
         settings: &WriteSettings,
     ) -> std::io::Result<bool> {
-        let mut any = false;
-        for attrib in self {
+        for (i, attrib) in self.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b" ")?;
+            }
             attrib.write_attribute(writer, settings)?;
-            any = true;
         }
-        Ok(any)
+        Ok(!self.is_empty())
     }
+
+    #[cfg(feature = "write")]
+    fn write_attribute_pairs(
+        &self,
+        pairs: &mut Vec<AttributePair>,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        for attrib in self {
+            pairs.push(attrib.write_attribute_pair(settings)?);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "read")]
+    fn read_attribute(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> Result<bool, crate::error::InvalidAttributeValue> {
+        match A::read_attribute(name, value) {
+            Some(it) => {
+                self.push(it);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+// `Box` can't be covered here as it's a fundamental type, so a downstream
+// `impl Attribute for Box<T>` would overlap with the blanket `Attribute`
+// implementation. Boxed bundles are still written through auto-deref.
+macro_rules! impl_bundle_deref {
+    ($($ptr: ident),*) => {$(
+        impl<T: AttributeBundle + ?Sized> AttributeBundle for $ptr<T> {
+            #[cfg(feature = "write")]
+            #[inline]
+            fn write_attributes<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+                settings: &WriteSettings,
+            ) -> std::io::Result<bool> {
+                T::write_attributes(self, writer, settings)
+            }
+
+            #[cfg(feature = "write")]
+            #[inline]
+            fn write_attribute_pairs(
+                &self,
+                pairs: &mut Vec<AttributePair>,
+                settings: &WriteSettings,
+            ) -> std::io::Result<()> {
+                T::write_attribute_pairs(self, pairs, settings)
+            }
+
+            #[cfg(feature = "read")]
+            fn read_attribute(
+                &mut self,
+                _name: &str,
+                _value: &str,
+            ) -> Result<bool, crate::error::InvalidAttributeValue> {
+                // shared bundles can't be mutated
+                Ok(false)
+            }
+        }
+    )*};
 }
+
+impl_bundle_deref![Rc, Arc];
@@ -1,13 +1,222 @@
+/// Implements `TryFrom<&str>` and, with the `read` feature,
+/// [`Readable`](io::Readable) for types implementing `FromStr` by delegating to
+/// `from_str`.
+macro_rules! impl_from_str_conversions {
+    ($target: ident<$lifetime: lifetime>) => {
+        impl<$lifetime> TryFrom<&str> for $target<$lifetime> {
+            type Error = <$target<$lifetime> as std::str::FromStr>::Err;
+
+            #[inline]
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        #[cfg(feature = "read")]
+        impl<$lifetime> crate::io::Readable for $target<$lifetime> {
+            #[inline]
+            fn read(value: &str) -> Option<Self> {
+                value.parse().ok()
+            }
+        }
+    };
+    ($target: ty) => {
+        impl TryFrom<&str> for $target {
+            type Error = <$target as std::str::FromStr>::Err;
+
+            #[inline]
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                value.parse()
+            }
+        }
+
+        #[cfg(feature = "read")]
+        impl crate::io::Readable for $target {
+            #[inline]
+            fn read(value: &str) -> Option<Self> {
+                value.parse().ok()
+            }
+        }
+    };
+}
+
+/// Implements `serde` `Serialize` and `Deserialize` for types implementing
+/// `ToString` and `FromStr`, so they're stored in their textual form.
+macro_rules! impl_serde_string {
+    ($target: ident<$lifetime: lifetime>) => {
+        #[cfg(feature = "serde")]
+        impl<$lifetime> serde::Serialize for $target<$lifetime> {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $lifetime> serde::Deserialize<'de> for $target<$lifetime> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+    ($target: ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $target {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(&self.to_string())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $target {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                value.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Implements [`Writable`](io::Writable) for [`AttributeValue`](io::AttributeValue)
+/// types by delegating to `AttributeValue::write_to`.
+macro_rules! impl_writable_attribute_value {
+    ($target: ty) => {
+        #[cfg(feature = "write")]
+        impl crate::io::Writable for $target {
+            #[inline]
+            fn write_to<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+                settings: &crate::io::WriteSettings,
+            ) -> std::io::Result<()> {
+                crate::io::AttributeValue::write_to(self, writer, settings)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> usize {
+                crate::io::AttributeValue::as_str(self)
+                    .map(str::len)
+                    .unwrap_or_default()
+            }
+
+            #[inline]
+            fn as_attribute_str(&self) -> Option<&str> {
+                crate::io::AttributeValue::as_str(self)
+            }
+        }
+    };
+}
+
+/// Defines a keyword enum, where each variant is written as a fixed keyword.
+///
+/// Generates `as_str`, `Display`, `FromStr` (with [`InvalidKeyword`](error::InvalidKeyword)
+/// error), [`AttributeValue`](io::AttributeValue) and [`Writable`](io::Writable)
+/// implementations from `Variant => "keyword"` mappings. `Default` is derived,
+/// so one of the variants must be marked with `#[default]`.
+macro_rules! keyword_enum {
+    (
+        $(#[$meta: meta])*
+        $vis: vis enum $name: ident {
+            $($(#[$variant_meta: meta])* $variant: ident => $keyword: literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl $name {
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $keyword),*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = crate::error::InvalidKeyword;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($keyword => Ok($name::$variant),)*
+                    _ => Err(crate::error::InvalidKeyword(s.to_string())),
+                }
+            }
+        }
+
+        impl_from_str_conversions!($name);
+        impl_serde_string!($name);
+
+        impl crate::io::FromStringUnsafe for $name {
+            unsafe fn from(value: String) -> Self {
+                value.parse().unwrap_or_default()
+            }
+        }
+
+        impl crate::io::AttributeValue for $name {
+            #[cfg(feature = "write")]
+            fn write_to<W: std::io::Write>(
+                &self,
+                writer: &mut W,
+                _settings: &crate::io::WriteSettings,
+            ) -> std::io::Result<()> {
+                writer.write_all(self.as_str().as_bytes())
+            }
+
+            fn as_str(&self) -> Option<&str> {
+                Some($name::as_str(self))
+            }
+        }
+
+        impl_writable_attribute_value!($name);
+    };
+}
+
+/// Implements `fill`, `stroke` and `stroke_width` shorthands for elements
+/// with a `painting` attribute bundle.
+macro_rules! impl_paint_shorthands {
+    ($target: ident) => {
+        impl<'a> $target<'a> {
+            /// Sets the `fill` attribute of this element.
+            #[inline]
+            pub fn fill(&mut self, paint: crate::style::Paint<'a>) {
+                self.painting.set_fill(paint);
+            }
+
+            /// Sets the `stroke` attribute of this element.
+            #[inline]
+            pub fn stroke(&mut self, paint: crate::style::Paint<'a>) {
+                self.painting.set_stroke(paint);
+            }
+
+            /// Sets the `stroke-width` attribute of this element.
+            #[inline]
+            pub fn stroke_width(&mut self, width: impl Into<crate::math::Length>) {
+                self.painting.set_stroke_width(width);
+            }
+        }
+    };
+}
+
+pub mod animation;
 pub mod common;
+pub mod container;
 pub mod error;
+pub mod filter;
 pub mod io;
+pub mod marker;
 pub mod math;
+pub mod paint;
 pub mod path;
 pub mod script;
+pub mod shape;
 pub mod style;
 pub mod svg;
-
-pub(crate) mod sealed {
-    pub trait Sealed {}
-    impl<T> Sealed for T {}
-}
+pub mod text;
@@ -0,0 +1,169 @@
+use std::{fmt::Display, str::FromStr};
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    common::{CoreAttributes, PreserveAspectRatio, Units},
+    container::Element,
+    error::InvalidAttributeValue,
+    math::{Length, NonNegativeLength, Number, ViewBox},
+};
+
+/// Rotation of a marker relative to the path it's placed on.
+///
+/// [SVG 2 documentation](https://www.w3.org/TR/SVG/painting.html#OrientAttribute)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Orient {
+    /// Marker is oriented along the direction of the path.
+    Auto,
+    /// Like [`Orient::Auto`], but the marker at the start of the path is
+    /// rotated by an additional 180 degrees.
+    ///
+    /// This value is part of SVG 2 specification.
+    AutoStartReverse,
+    /// Fixed angle in degrees.
+    Angle(Number),
+}
+
+impl Default for Orient {
+    fn default() -> Self {
+        Orient::Angle(0.0)
+    }
+}
+
+impl FromStr for Orient {
+    type Err = InvalidAttributeValue;
+
+    /// Parses `auto`, `auto-start-reverse` or an angle in degrees. Angle units
+    /// other than `deg` aren't supported.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "auto" => Ok(Orient::Auto),
+            "auto-start-reverse" => Ok(Orient::AutoStartReverse),
+            angle => {
+                let angle = angle.strip_suffix("deg").unwrap_or(angle);
+                angle
+                    .parse()
+                    .map(Orient::Angle)
+                    .map_err(|_| InvalidAttributeValue::new("orient", s))
+            }
+        }
+    }
+}
+
+impl_from_str_conversions!(Orient);
+impl_serde_string!(Orient);
+
+impl Display for Orient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orient::Auto => f.write_str("auto"),
+            Orient::AutoStartReverse => f.write_str("auto-start-reverse"),
+            Orient::Angle(angle) => angle.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for Orient {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Orient::Auto => writer.write_all(b"auto"),
+            Orient::AutoStartReverse => writer.write_all(b"auto-start-reverse"),
+            Orient::Angle(angle) => crate::io::write_number(writer, *angle, settings),
+        }
+    }
+}
+
+/// Defines graphics used for drawing arrowheads or polymarkers on a given
+/// `<path>`, `<line>`, `<polyline>` or `<polygon>` element.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementMarker<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#ViewBoxAttribute)
+    #[xml_attribute {
+        name: "viewBox",
+    }]
+    pub view_box: Option<ViewBox>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute)
+    #[xml_attribute {
+        name: "preserveAspectRatio",
+    }]
+    pub preserve_aspect_ratio: Option<PreserveAspectRatio>,
+
+    /// X coordinate of the marker reference point, which is aligned exactly at
+    /// the marker position.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerElementRefXAttribute)
+    #[xml_attribute {
+        name: "refX",
+    }]
+    pub ref_x: Option<Length>,
+
+    /// Y coordinate of the marker reference point, which is aligned exactly at
+    /// the marker position.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerElementRefYAttribute)
+    #[xml_attribute {
+        name: "refY",
+    }]
+    pub ref_y: Option<Length>,
+
+    /// Coordinate system for `markerWidth`, `markerHeight` and the contents
+    /// of the marker, either [`Units::StrokeWidth`] or
+    /// [`Units::UserSpaceOnUse`].
+    ///
+    /// Treated as [`Units::StrokeWidth`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerUnitsAttribute)
+    #[xml_attribute {
+        name: "markerUnits",
+    }]
+    pub marker_units: Option<Units>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerWidthAttribute)
+    #[xml_attribute {
+        name: "markerWidth",
+    }]
+    pub marker_width: Option<NonNegativeLength>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#MarkerHeightAttribute)
+    #[xml_attribute {
+        name: "markerHeight",
+    }]
+    pub marker_height: Option<NonNegativeLength>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#OrientAttribute)
+    #[xml_attribute]
+    pub orient: Option<Orient>,
+
+    pub children: Vec<Element<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementMarker<'_> {
+    fn size_hint(&self) -> usize {
+        "<marker></marker>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<marker")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "marker", settings)
+    }
+}
@@ -1,5 +1,12 @@
+use std::{fmt::Display, str::FromStr};
+
 use ordered_float::OrderedFloat;
 
+use crate::{
+    error::{InvalidAttributeValue, InvalidKeyword, InvalidLength, InvalidNumber},
+    io::*,
+};
+
 /// Floating point number representation re-exported to support precision
 /// switching.
 pub type Number = f32;
@@ -25,6 +32,11 @@ impl PositiveNumber {
         }
     }
 
+    /// Constructs a new number without checking its value.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be finite and not negative.
     #[inline]
     pub unsafe fn new_unchecked(value: Number) -> Self {
         PositiveNumber { inner: value }
@@ -39,6 +51,59 @@ impl PositiveNumber {
     pub fn into_inner(self) -> Number {
         self.inner
     }
+
+    /// Restricts this number to `[min, max]` interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`, same as [`Ord::clamp`].
+    #[inline]
+    pub fn clamp(self, min: PositiveNumber, max: PositiveNumber) -> PositiveNumber {
+        Ord::clamp(self, min, max)
+    }
+}
+
+impl TryFrom<Number> for PositiveNumber {
+    type Error = InvalidNumber;
+
+    fn try_from(value: Number) -> Result<Self, Self::Error> {
+        if value.is_nan() {
+            Err(InvalidNumber::NaN)
+        } else if value.is_infinite() {
+            Err(InvalidNumber::Infinite)
+        } else if value.is_sign_negative() {
+            Err(InvalidNumber::Negative)
+        } else {
+            Ok(PositiveNumber { inner: value })
+        }
+    }
+}
+
+impl TryFrom<f64> for PositiveNumber {
+    type Error = InvalidNumber;
+
+    /// Values that are too large to be represented as a [`Number`] are
+    /// treated as infinite.
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        PositiveNumber::try_from(value as Number)
+    }
+}
+
+impl FromStr for PositiveNumber {
+    type Err = InvalidNumber;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value: Number = s.trim().parse().map_err(|_| InvalidNumber::Malformed)?;
+        PositiveNumber::try_from(value)
+    }
+}
+
+impl From<PositiveNumber> for Number {
+    #[inline]
+    fn from(value: PositiveNumber) -> Self {
+        value.inner
+    }
 }
 
 impl PartialEq for PositiveNumber {
@@ -60,7 +125,7 @@ impl Eq for PositiveNumber {}
 impl PartialOrd for PositiveNumber {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        OrderedFloat(self.inner).partial_cmp(&OrderedFloat(other.inner))
+        Some(self.cmp(other))
     }
 }
 impl Ord for PositiveNumber {
@@ -86,14 +151,221 @@ impl std::ops::DerefMut for PositiveNumber {
     }
 }
 
+impl Display for PositiveNumber {
+    /// Formats the number with [`DEFAULT_PRECISION`] decimal places, unless
+    /// precision is specified by the format string.
+    ///
+    /// Formatting rounds to the nearest representable decimal, with ties
+    /// resolved to even digits (e.g. `1.25` formats as `1.2` with precision
+    /// `1`).
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        write!(f, "{:.prec$}", self.inner, prec = precision)
+    }
+}
+
+impl FromStringUnsafe for PositiveNumber {
+    unsafe fn from(value: String) -> Self {
+        value
+            .parse()
+            .ok()
+            .and_then(PositiveNumber::new)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "read")]
+impl Readable for PositiveNumber {
+    fn read(value: &str) -> Option<Self> {
+        value.trim().parse().ok().and_then(PositiveNumber::new)
+    }
+}
+
+impl AttributeValue for PositiveNumber {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write_number(writer, self.inner, settings)
+    }
+}
+
+impl_writable_attribute_value!(PositiveNumber);
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PositiveNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PositiveNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Number::deserialize(deserializer)?;
+        PositiveNumber::new(value).ok_or_else(|| {
+            serde::de::Error::custom(format!("expected a non-negative number, got {value}"))
+        })
+    }
+}
+
+#[cfg(feature = "read")]
+impl Readable for Number {
+    #[inline]
+    fn read(value: &str) -> Option<Self> {
+        value.trim().parse().ok().filter(|it: &Number| it.is_finite())
+    }
+}
+
 #[cfg(feature = "write")]
-impl crate::io::Writable for PositiveNumber {
+impl Writable for Number {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write_number(writer, *self, settings)
+    }
+}
+
+/// Position in a 2D coordinate system.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    pub x: Number,
+    pub y: Number,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+
+    #[inline]
+    pub const fn new(x: Number, y: Number) -> Self {
+        Point { x, y }
+    }
+
+    /// Returns a point linearly interpolated between `self` and `other`, where
+    /// `t` of `0` is `self` and `1` is `other`.
+    #[inline]
+    pub fn lerp(self, other: Point, t: Number) -> Point {
+        self + (other - self) * t
+    }
+
+    #[inline]
+    pub fn distance_to(self, other: Point) -> Number {
+        (other - self).length()
+    }
+}
+
+/// Written as a comma separated coordinate pair, like in point lists.
+#[cfg(feature = "write")]
+impl crate::io::Writable for Point {
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
         settings: &crate::io::WriteSettings,
     ) -> std::io::Result<()> {
-        write!(writer, "{:.prec$}", self.inner, prec = settings.precision)
+        crate::io::write_number(writer, self.x, settings)?;
+        writer.write_all(b",")?;
+        crate::io::write_number(writer, self.y, settings)
+    }
+}
+
+/// Direction and magnitude in a 2D coordinate system.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector {
+    pub x: Number,
+    pub y: Number,
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { x: 0.0, y: 0.0 };
+
+    #[inline]
+    pub const fn new(x: Number, y: Number) -> Self {
+        Vector { x, y }
+    }
+
+    #[inline]
+    pub fn length(self) -> Number {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns a vector of unit length pointing in the same direction, or
+    /// `None` if this vector has no length.
+    pub fn normalize(self) -> Option<Vector> {
+        let length = self.length();
+        if length > 0.0 && length.is_finite() {
+            Some(self * (1.0 / length))
+        } else {
+            None
+        }
+    }
+}
+
+impl std::ops::Add<Vector> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub<Vector> for Point {
+    type Output = Point;
+
+    #[inline]
+    fn sub(self, rhs: Vector) -> Point {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::Sub for Point {
+    type Output = Vector;
+
+    #[inline]
+    fn sub(self, rhs: Point) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::Add for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::Mul<Number> for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn mul(self, rhs: Number) -> Vector {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl std::ops::Neg for Vector {
+    type Output = Vector;
+
+    #[inline]
+    fn neg(self) -> Vector {
+        Vector::new(-self.x, -self.y)
     }
 }
 
@@ -107,6 +379,582 @@ impl crate::io::Writable for PositiveNumber {
 /// presentation attribute value, even if parsed input file was uppercase.
 ///
 /// [CSS2 specification](http://www.w3.org/TR/2008/REC-CSS2-20080411/syndata.html#length-units)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Unit {
+    Em,
+    Ex,
+    Px,
+    In,
+    Cm,
+    Mm,
+    Pt,
+    Pc,
+}
+
+impl Unit {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Em => "em",
+            Unit::Ex => "ex",
+            Unit::Px => "px",
+            Unit::In => "in",
+            Unit::Cm => "cm",
+            Unit::Mm => "mm",
+            Unit::Pt => "pt",
+            Unit::Pc => "pc",
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = InvalidKeyword;
+
+    /// Parses a unit identifier, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const UNITS: [Unit; 8] = [
+            Unit::Em,
+            Unit::Ex,
+            Unit::Px,
+            Unit::In,
+            Unit::Cm,
+            Unit::Mm,
+            Unit::Pt,
+            Unit::Pc,
+        ];
+        UNITS
+            .into_iter()
+            .find(|it| it.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| InvalidKeyword(s.to_string()))
+    }
+}
+
+impl_from_str_conversions!(Unit);
+impl_serde_string!(Unit);
+
+impl Display for Unit {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A distance measurement.
+///
+/// Length without a [`Unit`] is expressed in user units.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/types.html#DataTypeLength)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Length {
+    pub value: Number,
+    pub unit: Option<Unit>,
+}
+
+impl Length {
+    #[inline]
+    pub const fn new(value: Number, unit: Unit) -> Self {
+        Length {
+            value,
+            unit: Some(unit),
+        }
+    }
+
+    /// Returns this length converted to user units, assuming one user unit
+    /// is one pixel and 96 pixels make an inch.
+    ///
+    /// `None` is returned for font-relative units as their size depends on
+    /// the styling context.
+    #[inline]
+    pub fn to_user_units(&self) -> Option<Number> {
+        self.resolve(96.0, None)
+    }
+
+    /// Returns this length converted to user units, assuming one user unit
+    /// is one pixel and `dpi` pixels make an inch.
+    ///
+    /// Font-relative units are resolved against `font_size` in user units,
+    /// with `ex` taken to be half of `em`. `None` is returned for them if
+    /// `font_size` isn't known.
+    pub fn resolve(&self, dpi: Number, font_size: Option<Number>) -> Option<Number> {
+        let scale = match self.unit {
+            None | Some(Unit::Px) => 1.0,
+            Some(Unit::In) => dpi,
+            Some(Unit::Cm) => dpi / 2.54,
+            Some(Unit::Mm) => dpi / 25.4,
+            Some(Unit::Pt) => dpi / 72.0,
+            Some(Unit::Pc) => dpi / 6.0,
+            Some(Unit::Em) => font_size?,
+            Some(Unit::Ex) => font_size? / 2.0,
+        };
+        Some(self.value * scale)
+    }
+}
+
+impl From<Number> for Length {
+    #[inline]
+    fn from(value: Number) -> Self {
+        Length { value, unit: None }
+    }
+}
+
+impl Display for Length {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)?;
+        if let Some(unit) = self.unit {
+            f.write_str(unit.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Length {
+    type Err = InvalidLength;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let number = trimmed.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let value = number
+            .parse::<Number>()
+            .map_err(|_| InvalidLength(s.to_string()))?;
+        let unit = match &trimmed[number.len()..] {
+            "" => None,
+            unit => Some(unit.parse().map_err(|_| InvalidLength(s.to_string()))?),
+        };
+        Ok(Length { value, unit })
+    }
+}
+
+impl_from_str_conversions!(Length);
+impl_serde_string!(Length);
+
+impl FromStringUnsafe for Length {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for Length {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write_number(writer, self.value, settings)?;
+        if let Some(unit) = self.unit {
+            writer.write_all(unit.as_str().as_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+impl_writable_attribute_value!(Length);
+
+/// A fraction of some reference length, written with a `%` suffix.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/types.html#DataTypePercentage)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Percentage {
+    pub value: Number,
+}
+
+impl Percentage {
+    #[inline]
+    pub const fn new(value: Number) -> Self {
+        Percentage { value }
+    }
+
+    /// Returns this percentage of `reference`.
+    #[inline]
+    pub fn resolve(&self, reference: Number) -> Number {
+        self.value / 100.0 * reference
+    }
+}
+
+impl Display for Percentage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt(f)?;
+        f.write_str("%")
+    }
+}
+
+impl FromStr for Percentage {
+    type Err = InvalidLength;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+            .strip_suffix('%')
+            .and_then(|it| it.parse::<Number>().ok())
+            .map(Percentage::new)
+            .ok_or_else(|| InvalidLength(s.to_string()))
+    }
+}
+
+impl_from_str_conversions!(Percentage);
+impl_serde_string!(Percentage);
+
+impl FromStringUnsafe for Percentage {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for Percentage {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write_number(writer, self.value, settings)?;
+        writer.write_all(b"%")
+    }
+}
+
+impl_writable_attribute_value!(Percentage);
+
+/// A position along an axis, either as an absolute [`Length`] or as a
+/// [`Percentage`] of the viewport size.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/types.html#DataTypeCoordinate)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coordinate {
+    Length(Length),
+    Percentage(Percentage),
+}
+
+impl Coordinate {
+    /// Returns this coordinate in user units, see [`Length::to_user_units`].
+    ///
+    /// `None` is returned for percentages as the viewport size isn't known.
+    pub fn to_user_units(&self) -> Option<Number> {
+        match self {
+            Coordinate::Length(it) => it.to_user_units(),
+            Coordinate::Percentage(_) => None,
+        }
+    }
+
+    /// Returns this coordinate in user units, resolving percentages against
+    /// `reference` and lengths as described by [`Length::resolve`].
+    pub fn resolve(
+        &self,
+        reference: Number,
+        dpi: Number,
+        font_size: Option<Number>,
+    ) -> Option<Number> {
+        match self {
+            Coordinate::Length(it) => it.resolve(dpi, font_size),
+            Coordinate::Percentage(it) => Some(it.resolve(reference)),
+        }
+    }
+}
+
+impl Default for Coordinate {
+    #[inline]
+    fn default() -> Self {
+        Coordinate::Length(Length::default())
+    }
+}
+
+impl From<Number> for Coordinate {
+    #[inline]
+    fn from(value: Number) -> Self {
+        Coordinate::Length(value.into())
+    }
+}
+
+impl From<Length> for Coordinate {
+    #[inline]
+    fn from(value: Length) -> Self {
+        Coordinate::Length(value)
+    }
+}
+
+impl From<Percentage> for Coordinate {
+    #[inline]
+    fn from(value: Percentage) -> Self {
+        Coordinate::Percentage(value)
+    }
+}
+
+impl Display for Coordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Coordinate::Length(it) => it.fmt(f),
+            Coordinate::Percentage(it) => it.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Coordinate {
+    type Err = InvalidLength;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim_end().ends_with('%') {
+            s.parse().map(Coordinate::Percentage)
+        } else {
+            s.parse().map(Coordinate::Length)
+        }
+    }
+}
+
+impl_from_str_conversions!(Coordinate);
+impl_serde_string!(Coordinate);
+
+impl FromStringUnsafe for Coordinate {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for Coordinate {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Coordinate::Length(it) => AttributeValue::write_to(it, writer, settings),
+            Coordinate::Percentage(it) => AttributeValue::write_to(it, writer, settings),
+        }
+    }
+}
+
+impl_writable_attribute_value!(Coordinate);
+
+/// A [`Length`] that's guaranteed not to be negative, used by sizes and radii.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NonNegativeLength {
+    inner: Length,
+}
+
+impl NonNegativeLength {
+    /// Constructs a new length, or returns `None` if `value` is negative or not
+    /// finite.
+    pub fn new(value: Length) -> Option<Self> {
+        if PositiveNumber::is_valid(value.value) {
+            Some(NonNegativeLength { inner: value })
+        } else {
+            None
+        }
+    }
+
+    /// Constructs a new length without checking its value.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be finite and not negative. Other types rely on this
+    /// invariant, e.g. when computing sizes and radii.
+    #[inline]
+    pub unsafe fn new_unchecked(value: Length) -> Self {
+        NonNegativeLength { inner: value }
+    }
+
+    #[inline]
+    pub fn to_inner(&self) -> Length {
+        self.inner
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Length {
+        self.inner
+    }
+}
+
+impl std::ops::Deref for NonNegativeLength {
+    type Target = Length;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl From<PositiveNumber> for NonNegativeLength {
+    #[inline]
+    fn from(value: PositiveNumber) -> Self {
+        NonNegativeLength {
+            inner: value.into_inner().into(),
+        }
+    }
+}
+
+impl Display for NonNegativeLength {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl FromStr for NonNegativeLength {
+    type Err = InvalidLength;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NonNegativeLength::new(s.parse()?).ok_or_else(|| InvalidLength(s.to_string()))
+    }
+}
+
+impl_from_str_conversions!(NonNegativeLength);
+impl_serde_string!(NonNegativeLength);
+
+impl FromStringUnsafe for NonNegativeLength {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for NonNegativeLength {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        crate::io::Writable::write_to(&self.inner, writer, settings)
+    }
+}
+
+impl_writable_attribute_value!(NonNegativeLength);
+
+/// Axis-aligned rectangle described by its minimum and maximum corners.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    /// Constructs the smallest rectangle containing both `a` and `b`.
+    #[inline]
+    pub fn from_points(a: Point, b: Point) -> Self {
+        Rect {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> Number {
+        self.max.x - self.min.x
+    }
+
+    #[inline]
+    pub fn height(&self) -> Number {
+        self.max.y - self.min.y
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `other`.
+    #[inline]
+    pub fn union(self, other: Rect) -> Rect {
+        Rect {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// Returns the smallest rectangle containing both `self` and `point`.
+    #[inline]
+    pub fn include(self, point: Point) -> Rect {
+        self.union(Rect {
+            min: point,
+            max: point,
+        })
+    }
+}
+
+impl From<Rect> for ViewBox {
+    #[inline]
+    fn from(value: Rect) -> Self {
+        ViewBox::new(value.min.x, value.min.y, value.width(), value.height())
+    }
+}
+
+/// Geometry with an extent in user space.
+pub trait Bounded {
+    /// Returns the smallest axis-aligned rectangle containing the geometry,
+    /// or `None` if it has no geometry or its extent can't be determined.
+    ///
+    /// Stroke width isn't taken into account.
+    fn bounding_box(&self) -> Option<Rect>;
+}
+
+impl<T: Bounded> Bounded for [T] {
+    /// Returns the union of bounding boxes of all items.
+    fn bounding_box(&self) -> Option<Rect> {
+        self.iter()
+            .filter_map(Bounded::bounding_box)
+            .reduce(Rect::union)
+    }
+}
+
+impl<T: Bounded> Bounded for Vec<T> {
+    #[inline]
+    fn bounding_box(&self) -> Option<Rect> {
+        self.as_slice().bounding_box()
+    }
+}
 
+/// Rectangle in user space which is mapped to the bounds of the viewport.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#ViewBoxAttribute)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewBox {
+    pub min_x: Number,
+    pub min_y: Number,
+    pub width: Number,
+    pub height: Number,
+}
+
+impl ViewBox {
+    #[inline]
+    pub const fn new(min_x: Number, min_y: Number, width: Number, height: Number) -> Self {
+        ViewBox {
+            min_x,
+            min_y,
+            width,
+            height,
+        }
+    }
+}
+
+impl FromStr for ViewBox {
+    type Err = InvalidAttributeValue;
+
+    /// Parses four numbers separated by whitespace and/or a comma. Negative
+    /// width or height is rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidAttributeValue::new("viewBox", s);
+        let mut numbers = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|it| !it.is_empty())
+            .map(|it| it.parse::<Number>().map_err(|_| invalid()));
+        let mut next = || numbers.next().unwrap_or_else(|| Err(invalid()));
+        let result = ViewBox::new(next()?, next()?, next()?, next()?);
+        if numbers.next().is_some() || result.width < 0. || result.height < 0. {
+            return Err(invalid());
+        }
+        Ok(result)
+    }
+}
+
+impl_from_str_conversions!(ViewBox);
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ViewBox {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        let values = [self.min_x, self.min_y, self.width, self.height];
+        for (i, value) in values.into_iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b" ")?;
+            }
+            crate::io::write_number(writer, value, settings)?;
+        }
+        Ok(())
+    }
 }
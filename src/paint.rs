@@ -0,0 +1,116 @@
+use structuredvg_macros::BundleAttributes;
+
+pub use crate::common::Units;
+use crate::{
+    common::{ConditionalProcessing, CoreAttributes, PositionSize},
+    container::Element,
+};
+
+/// Defines a clipping path which restricts the region to which paint can be
+/// applied.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#ClipPathElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementClipPath<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Coordinate system for the contents of the element.
+    ///
+    /// Treated as [`Units::UserSpaceOnUse`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#ClipPathElementClipPathUnitsAttribute)
+    #[xml_attribute {
+        name: "clipPathUnits",
+    }]
+    pub clip_path_units: Option<Units>,
+
+    /// Shapes, text and `<use>` elements that form the clipping path.
+    pub children: Vec<Element<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementClipPath<'_> {
+    fn size_hint(&self) -> usize {
+        "<clipPath></clipPath>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<clipPath")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "clipPath", settings)
+    }
+}
+
+/// Defines an alpha mask for compositing the current object into the
+/// background.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#MaskElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementMask<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Coordinate system for `x`, `y`, `width` and `height` attributes.
+    ///
+    /// Treated as [`Units::ObjectBoundingBox`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#MaskElementMaskUnitsAttribute)
+    #[xml_attribute {
+        name: "maskUnits",
+    }]
+    pub mask_units: Option<Units>,
+
+    /// Coordinate system for the contents of the mask.
+    ///
+    /// Treated as [`Units::UserSpaceOnUse`] when not specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#MaskElementMaskContentUnitsAttribute)
+    #[xml_attribute {
+        name: "maskContentUnits",
+    }]
+    pub mask_content_units: Option<Units>,
+
+    /// Position and size of the mask, defaulting to `-10%`, `-10%`, `120%`
+    /// and `120%` respectively.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#MaskElementXAttribute)
+    #[xml_attribute_bundle]
+    pub position_size: Box<PositionSize>,
+
+    /// Graphics elements used as the mask.
+    pub children: Vec<Element<'a>>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementMask<'_> {
+    fn size_hint(&self) -> usize {
+        "<mask></mask>".len() + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<mask")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "mask", settings)
+    }
+}
@@ -1,17 +1,33 @@
 use crate::{
     common::{ConditionalProcessing, CoreAttributes},
     script::GraphicalEvents,
+    style::{PaintingAttributes, ReferenceProperties},
 };
 use structuredvg_macros::BundleAttributes;
 
-use crate::math::PositiveNumber;
+use crate::math::{Bounded, PositiveNumber, Rect};
+
+#[cfg(feature = "path")]
+mod geometry;
+#[cfg(feature = "path")]
+mod parse;
 
 #[cfg(feature = "path")]
 mod path_impl {
-    use crate::math::Number;
+    use ordered_float::OrderedFloat;
+    use smallvec::SmallVec;
+
+    use crate::{
+        error::PathError,
+        math::{Number, Point, Rect, Vector, ViewBox},
+        style::FillRule,
+    };
+
+    use super::geometry;
 
     /// Represents command types of [`CommandData`].
-    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     #[repr(u8)]
     pub enum Command {
         Move,
@@ -42,6 +58,27 @@ mod path_impl {
             }
         }
 
+        /// Returns the command represented by a path data command letter and
+        /// whether it's relative (lowercase).
+        ///
+        /// Both `z` and `Z` map to [`Command::Close`].
+        pub const fn from_char(c: char) -> Option<(Command, bool)> {
+            let command = match c.to_ascii_uppercase() {
+                'M' => Command::Move,
+                'L' => Command::Line,
+                'H' => Command::Horizontal,
+                'V' => Command::Vertical,
+                'C' => Command::Cubic,
+                'S' => Command::CubicSmooth,
+                'Q' => Command::Quadratic,
+                'T' => Command::QuadraticSmooth,
+                'A' => Command::Elliptical,
+                'Z' => Command::Close,
+                _ => return None,
+            };
+            Some((command, c.is_ascii_lowercase()))
+        }
+
         pub const fn absolute(&self) -> char {
             match self {
                 Command::Move => 'M',
@@ -78,7 +115,12 @@ mod path_impl {
     /// See [SVG 1.1](https://www.w3.org/TR/SVG11/paths.html#PathData) and
     /// [SVG 2](https://www.w3.org/TR/SVG/paths.html#PathData) documentation for
     /// details on what each command does
-    #[derive(Debug, Clone, Copy, PartialEq)]
+    ///
+    /// Arguments are compared and hashed as [`OrderedFloat`]s, which makes
+    /// `CommandData` [`Eq`], [`Ord`] and [`Hash`]: `-0.0` and `0.0` are equal
+    /// and `NaN` is equal to itself.
+    #[derive(Debug, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub enum CommandData {
         /// Move position without drawing any lines.
         ///
@@ -112,6 +154,45 @@ mod path_impl {
     }
 
     impl CommandData {
+        /// Constructs elliptical arc data with `large_arc` and `sweep` flags
+        /// stored as `0` or `1`.
+        #[inline]
+        pub const fn arc(
+            rx: Number,
+            ry: Number,
+            rotation: Number,
+            large_arc: bool,
+            sweep: bool,
+            x: Number,
+            y: Number,
+        ) -> Self {
+            CommandData::Elliptical([rx, ry, rotation, flag(large_arc), flag(sweep), x, y])
+        }
+
+        /// Returns the large arc flag of elliptical arc data, or `None` for
+        /// other commands.
+        ///
+        /// Any non-zero flag value is treated as set.
+        #[inline]
+        pub fn large_arc_flag(&self) -> Option<bool> {
+            match self {
+                CommandData::Elliptical(args) => Some(args[3] != 0.0),
+                _ => None,
+            }
+        }
+
+        /// Returns the sweep flag of elliptical arc data, or `None` for other
+        /// commands.
+        ///
+        /// Any non-zero flag value is treated as set.
+        #[inline]
+        pub fn sweep_flag(&self) -> Option<bool> {
+            match self {
+                CommandData::Elliptical(args) => Some(args[4] != 0.0),
+                _ => None,
+            }
+        }
+
         pub fn command(&self) -> Command {
             match self {
                 CommandData::Move(_) => Command::Move,
@@ -142,17 +223,190 @@ mod path_impl {
             }
         }
 
+        pub fn args_mut(&mut self) -> &mut [Number] {
+            match self {
+                CommandData::Move(args) => &mut args[..],
+                CommandData::Line(args) => &mut args[..],
+                CommandData::Horizontal(args) => &mut args[..],
+                CommandData::Vertical(args) => &mut args[..],
+                CommandData::Cubic(args) => &mut args[..],
+                CommandData::CubicSmooth(args) => &mut args[..],
+                CommandData::Quadratic(args) => &mut args[..],
+                CommandData::QuadraticSmooth(args) => &mut args[..],
+                CommandData::Elliptical(args) => &mut args[..],
+                CommandData::Close(args) => &mut args[..],
+            }
+        }
+
         pub fn len(&self) -> usize {
             self.command().argument_count()
         }
+
+        /// Returns `true` if the command takes no arguments (i.e. `Z`).
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
     }
 
-    #[derive(Debug, Clone, Copy, PartialEq)]
+    impl PartialEq for CommandData {
+        #[inline]
+        fn eq(&self, other: &Self) -> bool {
+            self.cmp(other) == std::cmp::Ordering::Equal
+        }
+    }
+
+    impl Eq for CommandData {}
+
+    impl PartialOrd for CommandData {
+        #[inline]
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for CommandData {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.command().cmp(&other.command()).then_with(|| {
+                let args = self.args().iter().map(|it| OrderedFloat(*it));
+                args.cmp(other.args().iter().map(|it| OrderedFloat(*it)))
+            })
+        }
+    }
+
+    impl std::hash::Hash for CommandData {
+        fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+            self.command().hash(state);
+            for arg in self.args() {
+                OrderedFloat(*arg).hash(state);
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct PathSegment {
         pub relative: bool,
         pub data: CommandData,
     }
 
+    /// Generates pairs of absolute and relative [`PathSegment`] constructors.
+    macro_rules! segment_constructors {
+        ($(
+            $(#[$meta: meta])*
+            $absolute: ident, $relative: ident => $variant: ident($($arg: ident),*);
+        )*) => {$(
+            $(#[$meta])*
+            #[inline]
+            pub const fn $absolute($($arg: Number),*) -> Self {
+                PathSegment::new(false, CommandData::$variant([$($arg),*]))
+            }
+
+            $(#[$meta])*
+            ///
+            /// Coordinates are relative to the current point.
+            #[inline]
+            pub const fn $relative($($arg: Number),*) -> Self {
+                PathSegment::new(true, CommandData::$variant([$($arg),*]))
+            }
+        )*};
+    }
+
+    impl PathSegment {
+        #[inline]
+        pub const fn new(relative: bool, data: CommandData) -> Self {
+            PathSegment { relative, data }
+        }
+
+        segment_constructors! {
+            /// Constructs a move segment.
+            move_to, rel_move_to => Move(x, y);
+            /// Constructs a line segment.
+            line, rel_line => Line(x, y);
+            /// Constructs a horizontal line segment.
+            horizontal, rel_horizontal => Horizontal(x);
+            /// Constructs a vertical line segment.
+            vertical, rel_vertical => Vertical(y);
+            /// Constructs a cubic Bézier curve segment.
+            cubic, rel_cubic => Cubic(x1, y1, x2, y2, x, y);
+            /// Constructs a smooth cubic Bézier curve segment.
+            smooth_cubic, rel_smooth_cubic => CubicSmooth(x2, y2, x, y);
+            /// Constructs a quadratic Bézier curve segment.
+            quadratic, rel_quadratic => Quadratic(x1, y1, x, y);
+            /// Constructs a smooth quadratic Bézier curve segment.
+            smooth_quadratic, rel_smooth_quadratic => QuadraticSmooth(x, y);
+        }
+
+        /// Constructs an elliptical arc segment.
+        #[inline]
+        pub const fn arc(
+            rx: Number,
+            ry: Number,
+            rotation: Number,
+            large_arc: bool,
+            sweep: bool,
+            x: Number,
+            y: Number,
+        ) -> Self {
+            PathSegment::new(
+                false,
+                CommandData::arc(rx, ry, rotation, large_arc, sweep, x, y),
+            )
+        }
+
+        /// Constructs an elliptical arc segment.
+        ///
+        /// Coordinates of the end point are relative to the current point.
+        #[inline]
+        pub const fn rel_arc(
+            rx: Number,
+            ry: Number,
+            rotation: Number,
+            large_arc: bool,
+            sweep: bool,
+            x: Number,
+            y: Number,
+        ) -> Self {
+            PathSegment::new(
+                true,
+                CommandData::arc(rx, ry, rotation, large_arc, sweep, x, y),
+            )
+        }
+
+        /// Constructs a close segment.
+        #[inline]
+        pub const fn close() -> Self {
+            PathSegment::new(false, CommandData::Close([]))
+        }
+
+        /// Returns the path data letter of this segment's command.
+        pub fn command_char(&self) -> char {
+            if self.relative {
+                self.data.command().relative()
+            } else {
+                self.data.command().absolute()
+            }
+        }
+    }
+
+    /// Returns arc flag argument value.
+    #[inline]
+    const fn flag(value: bool) -> Number {
+        if value {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    impl From<CommandData> for PathSegment {
+        /// Constructs an absolute segment from command `data`.
+        #[inline]
+        fn from(data: CommandData) -> Self {
+            PathSegment::new(false, data)
+        }
+    }
+
     #[cfg(feature = "write")]
     impl crate::io::Writable for PathSegment {
         fn write_to<W: std::io::Write>(
@@ -160,66 +414,19 @@ mod path_impl {
             writer: &mut W,
             settings: &crate::io::WriteSettings,
         ) -> std::io::Result<()> {
-            if self.relative {
-                writer.write(&[self.data.command().relative() as u8])?;
-            } else {
-                writer.write(&[self.data.command().absolute() as u8])?;
-            }
+            writer.write_all(&[self.command_char() as u8])?;
 
-            match self.data {
-                CommandData::Horizontal(it) | CommandData::Vertical(it) => {
-                    writer.write_fmt(format_args!(
-                        "{:.prec$}",
-                        it[0],
-                        prec = settings.precision
-                    ))?;
+            let arc = matches!(self.data, CommandData::Elliptical(_));
+            for (i, arg) in self.data.args().iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b" ")?;
                 }
-                CommandData::Move(it)
-                | CommandData::Line(it)
-                | CommandData::QuadraticSmooth(it) => {
-                    writer.write_fmt(format_args!(
-                        "{:.prec$} {:.prec$}",
-                        it[0],
-                        it[1],
-                        prec = settings.precision
-                    ))?;
+                if arc && (i == 3 || i == 4) {
+                    // flags must be written as single digits to be parsed back
+                    writer.write_all(if *arg != 0.0 { b"1" } else { b"0" })?;
+                } else {
+                    crate::io::write_number(writer, *arg, settings)?;
                 }
-                CommandData::CubicSmooth(it) | CommandData::Quadratic(it) => {
-                    writer.write_fmt(format_args!(
-                        "{:.prec$} {:.prec$} {:.prec$} {:.prec$}",
-                        it[0],
-                        it[1],
-                        it[2],
-                        it[3],
-                        prec = settings.precision
-                    ))?;
-                }
-                CommandData::Cubic(it) => {
-                    writer.write_fmt(format_args!(
-                        "{:.prec$} {:.prec$} {:.prec$} {:.prec$} {:.prec$} {:.prec$}",
-                        it[0],
-                        it[1],
-                        it[2],
-                        it[3],
-                        it[4],
-                        it[5],
-                        prec = settings.precision
-                    ))?;
-                }
-                CommandData::Elliptical(it) => {
-                    writer.write_fmt(format_args!(
-                        "{:.prec$} {:.prec$} {:.prec$} {:.prec$} {:.prec$} {:.prec$} {:.prec$}",
-                        it[0],
-                        it[1],
-                        it[2],
-                        it[3],
-                        it[4],
-                        it[5],
-                        it[6],
-                        prec = settings.precision
-                    ))?;
-                }
-                CommandData::Close(_) => {}
             }
 
             Ok(())
@@ -231,22 +438,924 @@ mod path_impl {
     /// See [SVG 1.1](https://www.w3.org/TR/SVG11/paths.html#PathData) and
     /// [SVG 2](https://www.w3.org/TR/SVG/paths.html#PathData) documentation for
     /// more details.
-    #[derive(Debug, Clone, PartialEq)]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct PathData {
         pub segments: Vec<PathSegment>,
     }
 
+    impl PathData {
+        /// Constructs an empty path with space for at least `capacity`
+        /// segments.
+        #[inline]
+        pub fn with_capacity(capacity: usize) -> Self {
+            PathData {
+                segments: Vec::with_capacity(capacity),
+            }
+        }
+
+        /// Shrinks segment storage of this path to fit its length.
+        #[inline]
+        pub fn shrink_to_fit(&mut self) {
+            self.segments.shrink_to_fit();
+        }
+
+        /// Appends a segment to the end of this path.
+        #[inline]
+        pub fn push(&mut self, segment: PathSegment) {
+            self.segments.push(segment);
+        }
+
+        /// Appends all segments of `other` path to the end of this one.
+        ///
+        /// Segments are copied as-is: if the first command of `other` is
+        /// relative, it's interpreted relative to the current point at the end
+        /// of this path, matching how SVG treats concatenated path data.
+        pub fn append(&mut self, other: &PathData) {
+            self.segments.extend_from_slice(&other.segments);
+        }
+
+        /// Checks that this path is valid SVG path data.
+        ///
+        /// Returns an error if path doesn't start with a move command, which
+        /// per SVG grammar makes the whole path data erroneous. Empty path
+        /// data is valid and disables rendering of the element.
+        pub fn validate(&self) -> Result<(), PathError> {
+            match self.segments.first() {
+                Some(segment) if segment.data.command() != Command::Move => {
+                    Err(PathError::MissingInitialMove)
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// Checks this path for legal constructs that usually indicate a
+        /// mistake.
+        ///
+        /// In addition to [validation](PathData::validate), returns an error
+        /// if:
+        /// - a close command isn't preceded by any drawing command in its
+        ///   subpath,
+        /// - a smooth curve command doesn't follow a curve of the same type.
+        ///   Renderers fall back to using the current point as the control
+        ///   point in that case.
+        ///
+        /// Parsing doesn't lint path data.
+        pub fn lint(&self) -> Result<(), PathError> {
+            let mut previous: Option<Command> = None;
+            let mut drawn = false;
+            for (index, segment) in self.segments.iter().enumerate() {
+                let command = segment.data.command();
+                match command {
+                    Command::Move => drawn = false,
+                    _ if previous.is_none() => return Err(PathError::MissingInitialMove),
+                    Command::Close => {
+                        if !drawn {
+                            return Err(PathError::CloseBeforeDraw(index));
+                        }
+                        drawn = false;
+                    }
+                    Command::CubicSmooth
+                        if !matches!(previous, Some(Command::Cubic | Command::CubicSmooth)) =>
+                    {
+                        return Err(PathError::OrphanSmooth(index));
+                    }
+                    Command::QuadraticSmooth
+                        if !matches!(
+                            previous,
+                            Some(Command::Quadratic | Command::QuadraticSmooth)
+                        ) =>
+                    {
+                        return Err(PathError::OrphanSmooth(index));
+                    }
+                    _ => drawn = true,
+                }
+                previous = Some(command);
+            }
+            Ok(())
+        }
+
+        /// Returns the number of segments of each command type, indexed by
+        /// `Command as usize`.
+        ///
+        /// Absolute and relative segments are counted together.
+        pub fn command_counts(&self) -> [usize; 10] {
+            let mut counts = [0; 10];
+            for segment in &self.segments {
+                counts[segment.data.command() as usize] += 1;
+            }
+            counts
+        }
+
+        /// Returns the total number of arguments of all segments, including
+        /// arc radii, rotations and flags.
+        pub fn coordinate_count(&self) -> usize {
+            self.segments.iter().map(|it| it.data.len()).sum()
+        }
+
+        /// Splits this path into subpaths, each starting with a move command.
+        ///
+        /// Segments are copied as-is, so a subpath starting with a relative
+        /// move is still relative to the end of the previous subpath.
+        pub fn subpaths(&self) -> Vec<PathData> {
+            let mut result: Vec<PathData> = Vec::new();
+            for segment in &self.segments {
+                match result.last_mut() {
+                    Some(current) if segment.data.command() != Command::Move => {
+                        current.segments.push(*segment);
+                    }
+                    _ => result.push(PathData {
+                        segments: vec![*segment],
+                    }),
+                }
+            }
+            result
+        }
+
+        /// Returns `true` if the last subpath of this path ends with a close
+        /// command.
+        pub fn is_closed(&self) -> bool {
+            matches!(
+                self.segments.last(),
+                Some(PathSegment {
+                    data: CommandData::Close(_),
+                    ..
+                })
+            )
+        }
+
+        /// Returns a path with reversed drawing direction that renders the
+        /// same shape.
+        ///
+        /// Returned path consists only of absolute move, line, cubic,
+        /// quadratic, arc and close commands. Closed subpaths remain closed
+        /// and start at the same point.
+        pub fn reverse(&self) -> PathData {
+            let mut segments = Vec::with_capacity(self.segments.len() + 1);
+            for subpath in geometry::resolve_subpaths(&self.segments).iter().rev() {
+                let end = subpath
+                    .curves
+                    .last()
+                    .map(|it| it.end())
+                    .unwrap_or(subpath.start);
+
+                if subpath.closed {
+                    segments.push(geometry::Curve::Move(subpath.start).to_segment());
+                    if end != subpath.start {
+                        segments.push(geometry::Curve::Line(subpath.start, end).to_segment());
+                    }
+                } else {
+                    segments.push(geometry::Curve::Move(end).to_segment());
+                }
+
+                for (i, curve) in subpath.curves.iter().enumerate().rev() {
+                    if subpath.closed && i == 0 && matches!(curve, geometry::Curve::Line(..)) {
+                        // line back to the start is drawn by the close command
+                        continue;
+                    }
+                    segments.push(curve.reversed().to_segment());
+                }
+
+                if subpath.closed {
+                    segments.push(geometry::Curve::Close(subpath.start, subpath.start).to_segment());
+                }
+            }
+            PathData { segments }
+        }
+
+        /// Returns segments of this path with relative coordinates resolved to
+        /// absolute ones.
+        ///
+        /// Unlike [`reverse`](PathData::reverse), command types are preserved.
+        pub(crate) fn absolute_segments(&self) -> Vec<PathSegment> {
+            let mut current = Point::ORIGIN;
+            let mut subpath_start = Point::ORIGIN;
+            self.segments
+                .iter()
+                .map(|segment| {
+                    let mut data = segment.data;
+                    if segment.relative {
+                        let (dx, dy) = (current.x, current.y);
+                        match &mut data {
+                            CommandData::Horizontal([x]) => *x += dx,
+                            CommandData::Vertical([y]) => *y += dy,
+                            CommandData::Elliptical([.., x, y]) => {
+                                *x += dx;
+                                *y += dy;
+                            }
+                            other => {
+                                for (i, arg) in other.args_mut().iter_mut().enumerate() {
+                                    *arg += if i % 2 == 0 { dx } else { dy };
+                                }
+                            }
+                        }
+                    }
+
+                    current = match data {
+                        CommandData::Horizontal([x]) => Point::new(x, current.y),
+                        CommandData::Vertical([y]) => Point::new(current.x, y),
+                        CommandData::Close(_) => subpath_start,
+                        other => match other.args() {
+                            [.., x, y] => Point::new(*x, *y),
+                            _ => current,
+                        },
+                    };
+                    if let CommandData::Move(_) = data {
+                        subpath_start = current;
+                    }
+
+                    PathSegment {
+                        relative: false,
+                        data,
+                    }
+                })
+                .collect()
+        }
+
+        /// Returns a copy of this path with relative coordinates resolved to
+        /// absolute ones.
+        ///
+        /// Command types are preserved, so smooth curves still rely on
+        /// reflected control points of preceding segments.
+        pub fn to_absolute(&self) -> PathData {
+            PathData {
+                segments: self.absolute_segments(),
+            }
+        }
+
+        /// Returns an iterator over commands of this path and their arguments
+        /// resolved to absolute coordinates.
+        ///
+        /// Implicit control points of smooth curves are made explicit, so
+        /// [`CubicSmooth`](Command::CubicSmooth) and
+        /// [`QuadraticSmooth`](Command::QuadraticSmooth) segments are yielded
+        /// as [`Cubic`](Command::Cubic) and [`Quadratic`](Command::Quadratic)
+        /// ones, while horizontal and vertical segments are yielded as
+        /// [`Line`](Command::Line)s.
+        pub fn iter_absolute(&self) -> impl Iterator<Item = (Command, SmallVec<[Number; 7]>)> + '_ {
+            self.segments
+                .iter()
+                .scan(geometry::Cursor::default(), |cursor, segment| {
+                    let data = cursor.advance(segment).to_segment().data;
+                    Some((data.command(), SmallVec::from_slice(data.args())))
+                })
+        }
+
+        /// Returns `true` if `other` path draws the same curves as this one,
+        /// with all coordinates within `epsilon` of each other.
+        ///
+        /// Paths are compared after resolving them to absolute coordinates, so
+        /// differences in relative and absolute encoding, horizontal and
+        /// vertical line shorthands and implicit control points of smooth
+        /// curves are ignored. Elliptical arcs are converted into cubic curves
+        /// before comparison, so an arc and its cubic approximation compare
+        /// equal. Other command types must match, a quadratic curve isn't
+        /// equal to an equivalent cubic one.
+        pub fn approx_eq(&self, other: &PathData, epsilon: Number) -> bool {
+            let (a, b) = (
+                geometry::resolve_curves(&self.segments),
+                geometry::resolve_curves(&other.segments),
+            );
+            a.len() == b.len() && a.iter().zip(&b).all(|(a, b)| a.approx_eq(b, epsilon))
+        }
+
+        /// Returns a hash of this path with coordinates rounded to `precision`
+        /// decimal places, for use as a cache key.
+        ///
+        /// The path is hashed after resolving it to absolute coordinates, as
+        /// done by [`iter_absolute`](PathData::iter_absolute), so differently
+        /// encoded paths drawing the same segments share a key. Hashes are
+        /// computed with FNV-1a and are stable across runs and platforms.
+        ///
+        /// This isn't a substitute for equality: distinct paths can collide,
+        /// and coordinates close to a rounding boundary can round apart. Use
+        /// [`approx_eq`](PathData::approx_eq) to compare paths.
+        pub fn content_hash(&self, precision: usize) -> u64 {
+            const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+            const PRIME: u64 = 0x0100_0000_01b3;
+
+            let scale = (10.0 as Number).powi(precision as i32);
+            let mut hash = OFFSET_BASIS;
+            let mut write = |bytes: &[u8]| {
+                for byte in bytes {
+                    hash = (hash ^ *byte as u64).wrapping_mul(PRIME);
+                }
+            };
+            for (command, args) in self.iter_absolute() {
+                write(&[command as u8]);
+                for arg in args {
+                    // -0.0 and 0.0 both become 0
+                    let rounded = (arg * scale).round() as i64;
+                    write(&rounded.to_le_bytes());
+                }
+            }
+            hash
+        }
+
+        /// Returns this path with runs of consecutive move commands collapsed
+        /// into a single move and a trailing move removed.
+        ///
+        /// Only the last move of a run has any effect. A collapsed move stays
+        /// relative only if all moves of the run are relative, in which case
+        /// their offsets are summed. Other segments are kept as they are.
+        pub fn dedup_moves(&self) -> PathData {
+            let mut segments = Vec::with_capacity(self.segments.len());
+            let mut pending: Option<PathSegment> = None;
+            for segment in &self.segments {
+                let CommandData::Move([x, y]) = segment.data else {
+                    segments.extend(pending.take());
+                    segments.push(*segment);
+                    continue;
+                };
+                pending = Some(match pending {
+                    Some(PathSegment {
+                        relative,
+                        data: CommandData::Move([px, py]),
+                    }) if segment.relative => {
+                        PathSegment::new(relative, CommandData::Move([px + x, py + y]))
+                    }
+                    _ => *segment,
+                });
+            }
+            PathData { segments }
+        }
+
+        /// Returns an equivalent path that's as short as possible when written
+        /// with [`DEFAULT_PRECISION`](crate::io::DEFAULT_PRECISION).
+        ///
+        /// See [`minify_with_precision`](PathData::minify_with_precision).
+        #[inline]
+        pub fn minify(&self) -> PathData {
+            self.minify_with_precision(crate::io::DEFAULT_PRECISION)
+        }
+
+        /// Returns an equivalent path that's as short as possible when written
+        /// with `precision` decimal places.
+        ///
+        /// All coordinates are rounded to `precision` first, after which each
+        /// segment is encoded with the shortest applicable command:
+        /// - lines parallel to an axis become horizontal and vertical ones,
+        /// - curves with a first control point reflecting the control point of
+        ///   a preceding curve of the same type become smooth ones,
+        /// - relative coordinates are used where they're written shorter than
+        ///   absolute ones.
+        ///
+        /// Rendered geometry is preserved within rounding to `precision`.
+        /// Relative coordinates are computed between already rounded points, so
+        /// rounding errors don't accumulate along the path. Returned path must
+        /// be written with the same `precision` for its size to be optimal.
+        pub fn minify_with_precision(&self, precision: usize) -> PathData {
+            let scale = (10.0 as Number).powi(precision as i32);
+            // adding zero turns negative zero positive, which is written shorter
+            let round = |value: Number| (value * scale).round() / scale + 0.0;
+            let round_point = |point: Point| Point::new(round(point.x), round(point.y));
+            let offset = |point: Point, from: Point| {
+                [round(point.x - from.x), round(point.y - from.y)]
+            };
+            let width = |data: &CommandData| -> usize {
+                let args = data.args();
+                let numbers: usize = args
+                    .iter()
+                    .map(|it| format!("{:.prec$}", it, prec = precision).len())
+                    .sum();
+                numbers + args.len().saturating_sub(1)
+            };
+
+            let mut cursor = geometry::Cursor::default();
+            let mut current = Point::ORIGIN;
+            let mut subpath_start = Point::ORIGIN;
+            let mut last_cubic: Option<Point> = None;
+            let mut last_quadratic: Option<Point> = None;
+            let mut segments = Vec::with_capacity(self.segments.len());
+            for segment in &self.segments {
+                let mut cubic = None;
+                let mut quadratic = None;
+                let (absolute, relative, to) = match cursor.advance(segment) {
+                    geometry::Curve::Move(to) => {
+                        let to = round_point(to);
+                        subpath_start = to;
+                        (
+                            CommandData::Move([to.x, to.y]),
+                            CommandData::Move(offset(to, current)),
+                            to,
+                        )
+                    }
+                    geometry::Curve::Line(_, to) => {
+                        let to = round_point(to);
+                        let [dx, dy] = offset(to, current);
+                        if to.y == current.y {
+                            (CommandData::Horizontal([to.x]), CommandData::Horizontal([dx]), to)
+                        } else if to.x == current.x {
+                            (CommandData::Vertical([to.y]), CommandData::Vertical([dy]), to)
+                        } else {
+                            (CommandData::Line([to.x, to.y]), CommandData::Line([dx, dy]), to)
+                        }
+                    }
+                    geometry::Curve::Quadratic(_, control, to) => {
+                        let (control, to) = (round_point(control), round_point(to));
+                        quadratic = Some(control);
+                        let [dx, dy] = offset(to, current);
+                        let smooth = last_quadratic
+                            .map(|it| round_point(current + (current - it)) == control)
+                            .unwrap_or(false);
+                        if smooth {
+                            (
+                                CommandData::QuadraticSmooth([to.x, to.y]),
+                                CommandData::QuadraticSmooth([dx, dy]),
+                                to,
+                            )
+                        } else {
+                            let [cx, cy] = offset(control, current);
+                            (
+                                CommandData::Quadratic([control.x, control.y, to.x, to.y]),
+                                CommandData::Quadratic([cx, cy, dx, dy]),
+                                to,
+                            )
+                        }
+                    }
+                    geometry::Curve::Cubic(_, c1, c2, to) => {
+                        let (c1, c2, to) = (round_point(c1), round_point(c2), round_point(to));
+                        cubic = Some(c2);
+                        let [c2x, c2y] = offset(c2, current);
+                        let [dx, dy] = offset(to, current);
+                        let smooth = last_cubic
+                            .map(|it| round_point(current + (current - it)) == c1)
+                            .unwrap_or(false);
+                        if smooth {
+                            (
+                                CommandData::CubicSmooth([c2.x, c2.y, to.x, to.y]),
+                                CommandData::CubicSmooth([c2x, c2y, dx, dy]),
+                                to,
+                            )
+                        } else {
+                            let [c1x, c1y] = offset(c1, current);
+                            (
+                                CommandData::Cubic([c1.x, c1.y, c2.x, c2.y, to.x, to.y]),
+                                CommandData::Cubic([c1x, c1y, c2x, c2y, dx, dy]),
+                                to,
+                            )
+                        }
+                    }
+                    geometry::Curve::Arc {
+                        radii,
+                        rotation,
+                        large_arc,
+                        sweep,
+                        to,
+                        ..
+                    } => {
+                        let to = round_point(to);
+                        let [dx, dy] = offset(to, current);
+                        let (rx, ry, rotation) = (round(radii.x), round(radii.y), round(rotation));
+                        let (large_arc, sweep) = (large_arc as u8 as Number, sweep as u8 as Number);
+                        (
+                            CommandData::Elliptical([
+                                rx, ry, rotation, large_arc, sweep, to.x, to.y,
+                            ]),
+                            CommandData::Elliptical([rx, ry, rotation, large_arc, sweep, dx, dy]),
+                            to,
+                        )
+                    }
+                    geometry::Curve::Close(_, _) => (
+                        CommandData::Close([]),
+                        CommandData::Close([]),
+                        subpath_start,
+                    ),
+                };
+
+                let relative_shorter = width(&relative) < width(&absolute);
+                segments.push(PathSegment {
+                    relative: relative_shorter,
+                    data: if relative_shorter { relative } else { absolute },
+                });
+                current = to;
+                last_cubic = cubic;
+                last_quadratic = quadratic;
+            }
+            PathData { segments }
+        }
+
+        /// Returns an absolute copy of this path with `x` applied to all
+        /// horizontal coordinates, `y` to all vertical ones and `radii` to arc
+        /// radii, rotation and flags.
+        pub(crate) fn map_coordinates(
+            &self,
+            x: impl Fn(Number) -> Number,
+            y: impl Fn(Number) -> Number,
+            radii: impl Fn(&mut [Number; 5]),
+        ) -> PathData {
+            let mut segments = self.absolute_segments();
+            for segment in &mut segments {
+                match &mut segment.data {
+                    CommandData::Horizontal([it]) => *it = x(*it),
+                    CommandData::Vertical([it]) => *it = y(*it),
+                    CommandData::Elliptical(args) => {
+                        let mut arc = [args[0], args[1], args[2], args[3], args[4]];
+                        radii(&mut arc);
+                        args[..5].copy_from_slice(&arc);
+                        args[5] = x(args[5]);
+                        args[6] = y(args[6]);
+                    }
+                    other => {
+                        for (i, arg) in other.args_mut().iter_mut().enumerate() {
+                            *arg = if i % 2 == 0 { x(*arg) } else { y(*arg) };
+                        }
+                    }
+                }
+            }
+            PathData { segments }
+        }
+
+        /// Returns this path moved by `dx` horizontally and `dy` vertically.
+        ///
+        /// Returned path uses absolute coordinates.
+        pub fn translate(&self, dx: Number, dy: Number) -> PathData {
+            self.map_coordinates(|x| x + dx, |y| y + dy, |_| {})
+        }
+
+        /// Returns this path scaled by `sx` horizontally and `sy` vertically,
+        /// relative to the origin.
+        ///
+        /// Returned path uses absolute coordinates. Arc radii are scaled and the
+        /// sweep flag is flipped if scaling mirrors the path. Arc rotation isn't
+        /// adjusted though, so non-uniform scaling is only exact for arcs
+        /// aligned with the axes (rotation of a multiple of 90°), as a rotated
+        /// ellipse stretched along an axis can't be described by new radii
+        /// alone.
+        pub fn scale(&self, sx: Number, sy: Number) -> PathData {
+            self.map_coordinates(
+                |x| x * sx,
+                |y| y * sy,
+                |[rx, ry, rotation, _, sweep]| {
+                    let axis_swapped = (*rotation / 90.0).round() as i64 % 2 != 0;
+                    let (scale_x, scale_y) = if axis_swapped { (sy, sx) } else { (sx, sy) };
+                    *rx *= scale_x.abs();
+                    *ry *= scale_y.abs();
+                    if sx * sy < 0.0 {
+                        *sweep = 1.0 - *sweep;
+                        *rotation = -*rotation;
+                    }
+                },
+            )
+        }
+
+        /// Returns this path with every coordinate rounded to the nearest
+        /// multiple of `grid`, such as `1.0` for whole pixels.
+        ///
+        /// Returned path uses absolute coordinates. Arc radii are rounded to
+        /// the grid too, but never down to zero as that would turn the arc into
+        /// a line, and negative radii are replaced by their absolute values as
+        /// renderers would. Arc rotation and flags are left unchanged.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `grid` isn't positive.
+        pub fn round_to_grid(&self, grid: Number) -> PathData {
+            assert!(grid > 0.0, "grid size must be positive");
+            let snap = |value: Number| (value / grid).round() * grid;
+            self.map_coordinates(snap, snap, |[rx, ry, ..]| {
+                if *rx != 0.0 {
+                    *rx = snap(rx.abs()).max(grid);
+                }
+                if *ry != 0.0 {
+                    *ry = snap(ry.abs()).max(grid);
+                }
+            })
+        }
+
+        /// Returns this path with every coordinate rounded to a whole number.
+        ///
+        /// Same as [`round_to_grid`](PathData::round_to_grid) with a grid of
+        /// `1.0`.
+        #[inline]
+        pub fn round_coordinates(&self) -> PathData {
+            self.round_to_grid(1.0)
+        }
+
+        /// Returns the bounding box of drawn segments of this path.
+        ///
+        /// Curves are flattened into line segments, so the returned box can be
+        /// smaller than the exact one by up to the flattening tolerance.
+        /// `None` is returned if the path draws nothing.
+        pub fn bounding_box(&self) -> Option<Rect> {
+            let mut result: Option<Rect> = None;
+            geometry::flatten_segments(
+                &self.segments,
+                geometry::FLATTENING_TOLERANCE,
+                |from, to| {
+                    let line = Rect::from_points(from, to);
+                    result = Some(result.map_or(line, |it| it.union(line)));
+                },
+            );
+            result
+        }
+
+        /// Returns a `viewBox` fitting this path with `padding` added on all
+        /// sides, or `None` if the path draws nothing.
+        ///
+        /// Negative `padding` shrinks the box, but its width and height are
+        /// never negative.
+        pub fn fit_view_box(&self, padding: Number) -> Option<ViewBox> {
+            let bounds = self.bounding_box()?;
+            Some(ViewBox::new(
+                bounds.min.x - padding,
+                bounds.min.y - padding,
+                (bounds.width() + 2. * padding).max(0.),
+                (bounds.height() + 2. * padding).max(0.),
+            ))
+        }
+
+        /// Returns the total length of drawn segments of this path.
+        ///
+        /// Curves are measured by flattening them into line segments, so the
+        /// returned value is an approximation.
+        pub fn length(&self) -> Number {
+            let mut length = 0.0;
+            geometry::flatten_segments(
+                &self.segments,
+                geometry::FLATTENING_TOLERANCE,
+                |from, to| length += from.distance_to(to),
+            );
+            length
+        }
+
+        /// Returns the position and unit tangent at `distance` along the path.
+        ///
+        /// `None` is returned if `distance` is negative or exceeds the
+        /// [`length`](PathData::length) of the path, or if the path has no
+        /// drawn segments.
+        pub fn point_at(&self, distance: Number) -> Option<(Point, Vector)> {
+            if distance < 0.0 {
+                return None;
+            }
+
+            let mut travelled = 0.0;
+            let mut result = None;
+            let mut last = None;
+            geometry::flatten_segments(
+                &self.segments,
+                geometry::FLATTENING_TOLERANCE,
+                |from, to| {
+                    if result.is_some() {
+                        return;
+                    }
+                    let tangent = match (to - from).normalize() {
+                        Some(it) => it,
+                        None => return,
+                    };
+                    let length = from.distance_to(to);
+                    if travelled + length >= distance {
+                        let t = (distance - travelled) / length;
+                        result = Some((from.lerp(to, t), tangent));
+                    } else {
+                        travelled += length;
+                        last = Some((to, tangent));
+                    }
+                },
+            );
+
+            // accumulated rounding error can leave the end point unmatched
+            result.or_else(|| last.filter(|_| distance - travelled <= Number::EPSILON * travelled))
+        }
+
+        /// Flattens this path into polygons, one for each subpath with drawn
+        /// segments, with curves approximated by lines within `tolerance`.
+        ///
+        /// Polygons are implicitly closed, so open subpaths are treated the
+        /// same way they're filled.
+        pub fn to_polygon_approximation(&self, tolerance: Number) -> Vec<Vec<Point>> {
+            geometry::flatten_polygons(&self.segments, tolerance)
+        }
+
+        /// Returns `true` if `point` is inside the area filled by this path
+        /// using `fill_rule`.
+        ///
+        /// Curves are flattened within `tolerance`, so points closer than that
+        /// to the outline can be misclassified. Empty paths contain no points.
+        pub fn contains_point(&self, point: Point, fill_rule: FillRule, tolerance: Number) -> bool {
+            let winding: i32 = self
+                .to_polygon_approximation(tolerance)
+                .iter()
+                .map(|polygon| geometry::winding_number(polygon, point))
+                .sum();
+            match fill_rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 != 0,
+            }
+        }
+    }
+
+    impl Extend<PathSegment> for PathData {
+        fn extend<T: IntoIterator<Item = PathSegment>>(&mut self, iter: T) {
+            self.segments.extend(iter)
+        }
+    }
+
+    impl From<Vec<PathSegment>> for PathData {
+        #[inline]
+        fn from(segments: Vec<PathSegment>) -> Self {
+            PathData { segments }
+        }
+    }
+
+    impl From<PathData> for Vec<PathSegment> {
+        #[inline]
+        fn from(path: PathData) -> Self {
+            path.segments
+        }
+    }
+
+    impl AsRef<[PathSegment]> for PathData {
+        #[inline]
+        fn as_ref(&self) -> &[PathSegment] {
+            &self.segments
+        }
+    }
+
+    impl std::ops::Deref for PathData {
+        type Target = [PathSegment];
+
+        #[inline]
+        fn deref(&self) -> &[PathSegment] {
+            &self.segments
+        }
+    }
+
+    impl std::ops::DerefMut for PathData {
+        #[inline]
+        fn deref_mut(&mut self) -> &mut [PathSegment] {
+            &mut self.segments
+        }
+    }
+
+    impl IntoIterator for PathData {
+        type Item = PathSegment;
+        type IntoIter = std::vec::IntoIter<PathSegment>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.segments.into_iter()
+        }
+    }
+
+    impl<'a> IntoIterator for &'a PathData {
+        type Item = &'a PathSegment;
+        type IntoIter = std::slice::Iter<'a, PathSegment>;
+
+        #[inline]
+        fn into_iter(self) -> Self::IntoIter {
+            self.segments.iter()
+        }
+    }
+
     #[cfg(feature = "write")]
     impl crate::io::Writable for PathData {
+        fn size_hint(&self) -> usize {
+            // command letter and roughly 8 bytes per argument
+            self.segments
+                .iter()
+                .map(|it| 1 + it.data.len() * 8)
+                .sum()
+        }
+
         fn write_to<W: std::io::Write>(
             &self,
             writer: &mut W,
             settings: &crate::io::WriteSettings,
         ) -> std::io::Result<()> {
-            for segment in &self.segments {
-                segment.write_to(writer, settings)?;
+            crate::io::write_wrapped(&self.segments, b"", writer, settings)
+        }
+    }
+
+    /// Settings that affect the `d` string of a [`CachedPath`].
+    #[cfg(feature = "write")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct CacheKey {
+        precision: usize,
+        max_line_width: Option<usize>,
+        continuation_indent: usize,
+        number_format: crate::io::NumberFormat,
+    }
+
+    #[cfg(feature = "write")]
+    impl CacheKey {
+        fn new(settings: &crate::io::WriteSettings) -> Self {
+            CacheKey {
+                precision: settings.precision,
+                max_line_width: settings.max_line_width,
+                continuation_indent: match settings.max_line_width {
+                    Some(_) => settings.indent.unwrap_or_default() * (settings.depth + 1),
+                    None => 0,
+                },
+                number_format: settings.number_format,
             }
-            Ok(())
+        }
+    }
+
+    /// [`PathData`] which caches its serialized `d` string, for paths that are
+    /// written many times without changing.
+    ///
+    /// The string is computed on first write and reused by later writes with
+    /// equivalent settings. Writes with different settings (e.g. precision)
+    /// serialize the path again without replacing the cached string.
+    ///
+    /// Path data can only be mutated through [`CachedPath::data_mut`], which
+    /// invalidates the cache.
+    #[cfg(feature = "write")]
+    #[derive(Debug, Clone)]
+    pub struct CachedPath {
+        data: PathData,
+        cache: std::cell::OnceCell<(CacheKey, String)>,
+    }
+
+    #[cfg(feature = "write")]
+    impl CachedPath {
+        #[inline]
+        pub fn new(data: PathData) -> Self {
+            CachedPath {
+                data,
+                cache: std::cell::OnceCell::new(),
+            }
+        }
+
+        /// Returns wrapped path data.
+        #[inline]
+        pub fn data(&self) -> &PathData {
+            &self.data
+        }
+
+        /// Returns mutable reference to wrapped path data and invalidates the
+        /// cached `d` string.
+        #[inline]
+        pub fn data_mut(&mut self) -> &mut PathData {
+            self.cache.take();
+            &mut self.data
+        }
+
+        #[inline]
+        pub fn into_inner(self) -> PathData {
+            self.data
+        }
+
+        /// Returns the `d` string of this path written with `settings`.
+        ///
+        /// Borrows the cached string if it was written with equivalent
+        /// settings, or if the cache is empty.
+        pub fn to_d_string(&self, settings: &crate::io::WriteSettings) -> std::borrow::Cow<'_, str> {
+            use crate::io::Writable;
+
+            let key = CacheKey::new(settings);
+            let (cached_key, cached) = self
+                .cache
+                .get_or_init(|| (key, self.data.write_to_string(settings)));
+            if *cached_key == key {
+                std::borrow::Cow::Borrowed(cached)
+            } else {
+                std::borrow::Cow::Owned(self.data.write_to_string(settings))
+            }
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl From<PathData> for CachedPath {
+        #[inline]
+        fn from(data: PathData) -> Self {
+            CachedPath::new(data)
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl PartialEq for CachedPath {
+        fn eq(&self, other: &Self) -> bool {
+            self.data == other.data
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl std::ops::Deref for CachedPath {
+        type Target = PathData;
+
+        #[inline]
+        fn deref(&self) -> &PathData {
+            &self.data
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl crate::io::Writable for CachedPath {
+        fn size_hint(&self) -> usize {
+            match self.cache.get() {
+                Some((_, cached)) => cached.len(),
+                None => self.data.size_hint(),
+            }
+        }
+
+        fn write_to<W: std::io::Write>(
+            &self,
+            writer: &mut W,
+            settings: &crate::io::WriteSettings,
+        ) -> std::io::Result<()> {
+            writer.write_all(self.to_d_string(settings).as_bytes())
         }
     }
 }
@@ -258,7 +1367,8 @@ type PathDataImpl<'a> = path_impl::PathData;
 #[cfg(not(feature = "path"))]
 type PathDataImpl<'a> = std::borrow::Cow<'a, str>;
 
-#[derive(Debug, Clone, BundleAttributes)]
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ElementPath<'a> {
     /// Conditional processing attributes.
     #[xml_attribute_bundle]
@@ -272,6 +1382,14 @@ pub struct ElementPath<'a> {
     #[xml_attribute_bundle]
     pub graphical_event: Box<GraphicalEvents<'a>>,
 
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
     /// Specifies shape of the path.
     ///
     /// - [SVG 1.1 Documentation](https://www.w3.org/TR/SVG11/paths.html#DAttribute)
@@ -283,20 +1401,44 @@ pub struct ElementPath<'a> {
     ///
     /// - [SVG 1.1 Documentation](https://www.w3.org/TR/SVG11/paths.html#PathLengthAttribute)
     /// - [SVG 2 Documentation](https://www.w3.org/TR/SVG/paths.html#PathLengthAttribute)
-    #[xml_attribute]
+    #[xml_attribute {
+        name: "pathLength",
+    }]
     pub path_length: Option<PositiveNumber>,
 }
 
+impl_paint_shorthands!(ElementPath);
+
+impl Bounded for ElementPath<'_> {
+    /// Returns the bounding box of path data.
+    ///
+    /// Without the `path` feature, path data isn't interpreted and `None` is
+    /// always returned.
+    fn bounding_box(&self) -> Option<Rect> {
+        #[cfg(feature = "path")]
+        {
+            self.d.as_ref()?.bounding_box()
+        }
+        #[cfg(not(feature = "path"))]
+        {
+            None
+        }
+    }
+}
+
 #[cfg(feature = "write")]
 impl crate::io::Writable for ElementPath<'_> {
+    fn size_hint(&self) -> usize {
+        "<path d=\"\"/>".len() + self.d.as_ref().map(|it| it.size_hint()).unwrap_or_default()
+    }
+
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
         settings: &crate::io::WriteSettings,
     ) -> std::io::Result<()> {
-        writer.write(b"<path ")?;
-        crate::io::AttributeBundle::write_attributes(self, writer, settings)?;
-        writer.write(b"/>")?;
-        Ok(())
+        writer.write_all(b"<path")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "path", settings)
     }
 }
@@ -0,0 +1,512 @@
+//! Geometric evaluation of path data.
+//!
+//! Segments are first resolved into absolute [`Curve`]s with all implicit
+//! control points made explicit, which are then flattened into line segments
+//! for measurement.
+
+use crate::math::{Number, Point, Vector};
+
+use super::{CommandData, PathSegment};
+
+/// Maximum distance between a curve and the lines approximating it.
+pub(crate) const FLATTENING_TOLERANCE: Number = 0.01;
+
+/// Recursion limit for curve subdivision.
+const MAX_SUBDIVISION_DEPTH: usize = 16;
+
+/// Path segment resolved to absolute coordinates, with control points of
+/// smooth commands made explicit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Curve {
+    Move(Point),
+    Line(Point, Point),
+    Quadratic(Point, Point, Point),
+    Cubic(Point, Point, Point, Point),
+    Arc {
+        from: Point,
+        radii: Vector,
+        rotation: Number,
+        large_arc: bool,
+        sweep: bool,
+        to: Point,
+    },
+    /// Line from the current point to the start of current subpath.
+    Close(Point, Point),
+}
+
+/// Keeps track of the state needed to resolve relative and smooth commands.
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct Cursor {
+    pub current: Point,
+    pub subpath_start: Point,
+    last_cubic_control: Option<Point>,
+    last_quadratic_control: Option<Point>,
+}
+
+impl Cursor {
+    /// Resolves `segment` against the current state and advances to its end.
+    pub fn advance(&mut self, segment: &PathSegment) -> Curve {
+        let from = self.current;
+        let offset = if segment.relative {
+            Vector::new(from.x, from.y)
+        } else {
+            Vector::ZERO
+        };
+        let point = |x: Number, y: Number| Point::new(x, y) + offset;
+
+        let mut cubic_control = None;
+        let mut quadratic_control = None;
+        let curve = match segment.data {
+            CommandData::Move([x, y]) => {
+                let to = point(x, y);
+                self.subpath_start = to;
+                Curve::Move(to)
+            }
+            CommandData::Line([x, y]) => Curve::Line(from, point(x, y)),
+            CommandData::Horizontal([x]) => {
+                let x = if segment.relative { from.x + x } else { x };
+                Curve::Line(from, Point::new(x, from.y))
+            }
+            CommandData::Vertical([y]) => {
+                let y = if segment.relative { from.y + y } else { y };
+                Curve::Line(from, Point::new(from.x, y))
+            }
+            CommandData::Cubic([x1, y1, x2, y2, x, y]) => {
+                let c2 = point(x2, y2);
+                cubic_control = Some(c2);
+                Curve::Cubic(from, point(x1, y1), c2, point(x, y))
+            }
+            CommandData::CubicSmooth([x2, y2, x, y]) => {
+                let c1 = reflect(self.last_cubic_control, from);
+                let c2 = point(x2, y2);
+                cubic_control = Some(c2);
+                Curve::Cubic(from, c1, c2, point(x, y))
+            }
+            CommandData::Quadratic([x1, y1, x, y]) => {
+                let c = point(x1, y1);
+                quadratic_control = Some(c);
+                Curve::Quadratic(from, c, point(x, y))
+            }
+            CommandData::QuadraticSmooth([x, y]) => {
+                let c = reflect(self.last_quadratic_control, from);
+                quadratic_control = Some(c);
+                Curve::Quadratic(from, c, point(x, y))
+            }
+            CommandData::Elliptical([rx, ry, rotation, large_arc, sweep, x, y]) => Curve::Arc {
+                from,
+                radii: Vector::new(rx, ry),
+                rotation,
+                large_arc: large_arc != 0.0,
+                sweep: sweep != 0.0,
+                to: point(x, y),
+            },
+            CommandData::Close(_) => Curve::Close(from, self.subpath_start),
+        };
+
+        self.current = curve.end();
+        self.last_cubic_control = cubic_control;
+        self.last_quadratic_control = quadratic_control;
+        curve
+    }
+}
+
+/// Reflects `control` point of the previous segment about `current` point.
+#[inline]
+fn reflect(control: Option<Point>, current: Point) -> Point {
+    match control {
+        Some(control) => current + (current - control),
+        None => current,
+    }
+}
+
+impl Curve {
+    pub fn start(&self) -> Point {
+        match *self {
+            Curve::Move(to) => to,
+            Curve::Line(from, _) => from,
+            Curve::Quadratic(from, _, _) => from,
+            Curve::Cubic(from, _, _, _) => from,
+            Curve::Arc { from, .. } => from,
+            Curve::Close(from, _) => from,
+        }
+    }
+
+    pub fn end(&self) -> Point {
+        match *self {
+            Curve::Move(to) => to,
+            Curve::Line(_, to) => to,
+            Curve::Quadratic(_, _, to) => to,
+            Curve::Cubic(_, _, _, to) => to,
+            Curve::Arc { to, .. } => to,
+            Curve::Close(_, to) => to,
+        }
+    }
+
+    /// Returns the same curve drawn in the opposite direction.
+    pub fn reversed(&self) -> Curve {
+        match *self {
+            Curve::Move(to) => Curve::Move(to),
+            Curve::Line(from, to) => Curve::Line(to, from),
+            Curve::Quadratic(p0, p1, p2) => Curve::Quadratic(p2, p1, p0),
+            Curve::Cubic(p0, p1, p2, p3) => Curve::Cubic(p3, p2, p1, p0),
+            Curve::Arc {
+                from,
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => Curve::Arc {
+                from: to,
+                radii,
+                rotation,
+                large_arc,
+                sweep: !sweep,
+                to: from,
+            },
+            Curve::Close(from, to) => Curve::Line(to, from),
+        }
+    }
+
+    /// Returns an absolute path segment drawing this curve from the current
+    /// point.
+    pub fn to_segment(self) -> PathSegment {
+        let data = match self {
+            Curve::Move(to) => CommandData::Move([to.x, to.y]),
+            Curve::Line(_, to) => CommandData::Line([to.x, to.y]),
+            Curve::Quadratic(_, c, to) => CommandData::Quadratic([c.x, c.y, to.x, to.y]),
+            Curve::Cubic(_, c1, c2, to) => {
+                CommandData::Cubic([c1.x, c1.y, c2.x, c2.y, to.x, to.y])
+            }
+            Curve::Arc {
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+                ..
+            } => CommandData::Elliptical([
+                radii.x,
+                radii.y,
+                rotation,
+                large_arc as u8 as Number,
+                sweep as u8 as Number,
+                to.x,
+                to.y,
+            ]),
+            Curve::Close(_, _) => CommandData::Close([]),
+        };
+        PathSegment {
+            relative: false,
+            data,
+        }
+    }
+
+    /// Returns `true` if `other` curve is of the same type and all of its
+    /// points are within `epsilon` of the points of this one on both axes.
+    ///
+    /// Arcs are never equal, they're expected to be converted into cubic curves
+    /// beforehand.
+    pub fn approx_eq(&self, other: &Curve, epsilon: Number) -> bool {
+        let near = |a: Point, b: Point| {
+            (a.x - b.x).abs() <= epsilon && (a.y - b.y).abs() <= epsilon
+        };
+        match (*self, *other) {
+            (Curve::Move(a), Curve::Move(b)) => near(a, b),
+            (Curve::Line(a0, a1), Curve::Line(b0, b1))
+            | (Curve::Close(a0, a1), Curve::Close(b0, b1)) => near(a0, b0) && near(a1, b1),
+            (Curve::Quadratic(a0, a1, a2), Curve::Quadratic(b0, b1, b2)) => {
+                near(a0, b0) && near(a1, b1) && near(a2, b2)
+            }
+            (Curve::Cubic(a0, a1, a2, a3), Curve::Cubic(b0, b1, b2, b3)) => {
+                near(a0, b0) && near(a1, b1) && near(a2, b2) && near(a3, b3)
+            }
+            _ => false,
+        }
+    }
+
+    /// Calls `line` for each line segment approximating this curve within
+    /// `tolerance`.
+    ///
+    /// Moves produce no lines.
+    pub fn flatten(&self, tolerance: Number, line: &mut impl FnMut(Point, Point)) {
+        match *self {
+            Curve::Move(_) => {}
+            Curve::Line(from, to) | Curve::Close(from, to) => line(from, to),
+            Curve::Quadratic(p0, p1, p2) => {
+                let (c1, c2) = elevate_quadratic(p0, p1, p2);
+                flatten_cubic(p0, c1, c2, p2, tolerance, 0, line);
+            }
+            Curve::Cubic(p0, p1, p2, p3) => flatten_cubic(p0, p1, p2, p3, tolerance, 0, line),
+            Curve::Arc {
+                from,
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => arc_to_cubics(from, radii, rotation, large_arc, sweep, to, &mut |p0, p1, p2, p3| {
+                flatten_cubic(p0, p1, p2, p3, tolerance, 0, line)
+            }),
+        }
+    }
+}
+
+/// Returns control points of a cubic curve equivalent to a quadratic one.
+#[inline]
+pub(crate) fn elevate_quadratic(p0: Point, p1: Point, p2: Point) -> (Point, Point) {
+    (
+        p0 + (p1 - p0) * (2.0 / 3.0),
+        p2 + (p1 - p2) * (2.0 / 3.0),
+    )
+}
+
+fn flatten_cubic(
+    p0: Point,
+    p1: Point,
+    p2: Point,
+    p3: Point,
+    tolerance: Number,
+    depth: usize,
+    line: &mut impl FnMut(Point, Point),
+) {
+    // Distance of control points from the chord, see: Roger Willcocks,
+    // "Sub-dividing Bezier curves".
+    let u = (p1 - p0) * 3.0 - (p3 - p0);
+    let v = (p2 - p3) * 3.0 - (p0 - p3);
+    let flatness = u.x.powi(2).max(v.x.powi(2)) + u.y.powi(2).max(v.y.powi(2));
+
+    if depth >= MAX_SUBDIVISION_DEPTH || flatness <= 16.0 * tolerance * tolerance {
+        line(p0, p3);
+        return;
+    }
+
+    let p01 = p0.lerp(p1, 0.5);
+    let p12 = p1.lerp(p2, 0.5);
+    let p23 = p2.lerp(p3, 0.5);
+    let p012 = p01.lerp(p12, 0.5);
+    let p123 = p12.lerp(p23, 0.5);
+    let mid = p012.lerp(p123, 0.5);
+
+    flatten_cubic(p0, p01, p012, mid, tolerance, depth + 1, line);
+    flatten_cubic(mid, p123, p23, p3, tolerance, depth + 1, line);
+}
+
+/// Decomposes an endpoint parameterized elliptical arc into cubic curves
+/// spanning at most a quarter turn each.
+///
+/// Follows [SVG 1.1 implementation notes](https://www.w3.org/TR/SVG11/implnote.html#ArcImplementationNotes)
+/// for out-of-range parameters.
+pub(crate) fn arc_to_cubics(
+    from: Point,
+    radii: Vector,
+    rotation: Number,
+    large_arc: bool,
+    sweep: bool,
+    to: Point,
+    cubic: &mut impl FnMut(Point, Point, Point, Point),
+) {
+    if from == to {
+        return;
+    }
+    let (mut rx, mut ry) = (radii.x.abs(), radii.y.abs());
+    if rx == 0.0 || ry == 0.0 {
+        let (c1, c2) = (from.lerp(to, 1.0 / 3.0), from.lerp(to, 2.0 / 3.0));
+        cubic(from, c1, c2, to);
+        return;
+    }
+
+    let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+    let half = (from - to) * 0.5;
+    let x1 = cos_phi * half.x + sin_phi * half.y;
+    let y1 = -sin_phi * half.x + cos_phi * half.y;
+
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    if lambda > 1.0 {
+        let scale = lambda.sqrt();
+        rx *= scale;
+        ry *= scale;
+    }
+
+    let numerator = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let denominator = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+    if large_arc == sweep {
+        coefficient = -coefficient;
+    }
+    let cx1 = coefficient * rx * y1 / ry;
+    let cy1 = -coefficient * ry * x1 / rx;
+
+    let mid = from.lerp(to, 0.5);
+    let center = Point::new(
+        cos_phi * cx1 - sin_phi * cy1 + mid.x,
+        sin_phi * cx1 + cos_phi * cy1 + mid.y,
+    );
+
+    let start = Vector::new((x1 - cx1) / rx, (y1 - cy1) / ry);
+    let end = Vector::new((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+    let theta = start.y.atan2(start.x);
+    let mut delta = end.y.atan2(end.x) - theta;
+    if sweep && delta < 0.0 {
+        delta += std::f32::consts::TAU as Number;
+    } else if !sweep && delta > 0.0 {
+        delta -= std::f32::consts::TAU as Number;
+    }
+
+    let count = (delta.abs() / std::f32::consts::FRAC_PI_2 as Number)
+        .ceil()
+        .max(1.0) as usize;
+    let step = delta / count as Number;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+
+    let map = |x: Number, y: Number| {
+        let (x, y) = (x * rx, y * ry);
+        Point::new(
+            cos_phi * x - sin_phi * y + center.x,
+            sin_phi * x + cos_phi * y + center.y,
+        )
+    };
+
+    let mut angle = theta;
+    let mut current = from;
+    for i in 0..count {
+        let next_angle = angle + step;
+        let (sin_a, cos_a) = angle.sin_cos();
+        let (sin_b, cos_b) = next_angle.sin_cos();
+        let c1 = map(cos_a - k * sin_a, sin_a + k * cos_a);
+        let c2 = map(cos_b + k * sin_b, sin_b - k * cos_b);
+        let end = if i + 1 == count {
+            to
+        } else {
+            map(cos_b, sin_b)
+        };
+        cubic(current, c1, c2, end);
+        current = end;
+        angle = next_angle;
+    }
+}
+
+/// Calls `line` for each line segment approximating drawn parts of
+/// `segments`.
+pub(crate) fn flatten_segments(
+    segments: &[PathSegment],
+    tolerance: Number,
+    mut line: impl FnMut(Point, Point),
+) {
+    let mut cursor = Cursor::default();
+    for segment in segments {
+        cursor.advance(segment).flatten(tolerance, &mut line);
+    }
+}
+
+/// Flattens `segments` into polygons, one for each subpath with drawn
+/// segments.
+///
+/// Polygons are implicitly closed; the edge from the last point back to the
+/// first one isn't repeated.
+pub(crate) fn flatten_polygons(segments: &[PathSegment], tolerance: Number) -> Vec<Vec<Point>> {
+    let mut result: Vec<Vec<Point>> = Vec::new();
+    let mut open = false;
+    let mut cursor = Cursor::default();
+    for segment in segments {
+        match cursor.advance(segment) {
+            Curve::Move(_) | Curve::Close(..) => open = false,
+            curve => curve.flatten(tolerance, &mut |from, to| {
+                if !open {
+                    result.push(vec![from]);
+                    open = true;
+                }
+                if let Some(polygon) = result.last_mut() {
+                    polygon.push(to);
+                }
+            }),
+        }
+    }
+    result
+}
+
+/// Returns the winding number of `polygon` around `point`.
+///
+/// Follows Dan Sunday's crossing algorithm, where upward edges crossing to
+/// the right of `point` count as `+1` and downward ones as `-1`.
+pub(crate) fn winding_number(polygon: &[Point], point: Point) -> i32 {
+    let mut winding = 0;
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    for (&a, &b) in edges {
+        let side = (b.x - a.x) * (point.y - a.y) - (point.x - a.x) * (b.y - a.y);
+        if a.y <= point.y {
+            if b.y > point.y && side > 0.0 {
+                winding += 1;
+            }
+        } else if b.y <= point.y && side < 0.0 {
+            winding -= 1;
+        }
+    }
+    winding
+}
+
+/// Resolves `segments` into absolute curves, with arcs converted into cubic
+/// curves.
+pub(crate) fn resolve_curves(segments: &[PathSegment]) -> Vec<Curve> {
+    let mut result = Vec::with_capacity(segments.len());
+    let mut cursor = Cursor::default();
+    for segment in segments {
+        match cursor.advance(segment) {
+            Curve::Arc {
+                from,
+                radii,
+                rotation,
+                large_arc,
+                sweep,
+                to,
+            } => arc_to_cubics(from, radii, rotation, large_arc, sweep, to, &mut |p0, p1, p2, p3| {
+                result.push(Curve::Cubic(p0, p1, p2, p3))
+            }),
+            curve => result.push(curve),
+        }
+    }
+    result
+}
+
+/// Sequence of curves starting at the same point.
+#[derive(Debug, Clone)]
+pub(crate) struct Subpath {
+    pub start: Point,
+    pub curves: Vec<Curve>,
+    pub closed: bool,
+}
+
+/// Resolves `segments` into subpaths of absolute curves.
+///
+/// Drawing commands following a close command without an explicit move start
+/// a new subpath at the start of the closed one.
+pub(crate) fn resolve_subpaths(segments: &[PathSegment]) -> Vec<Subpath> {
+    let mut result: Vec<Subpath> = Vec::new();
+    let mut cursor = Cursor::default();
+    for segment in segments {
+        let curve = cursor.advance(segment);
+        match curve {
+            Curve::Move(start) => result.push(Subpath {
+                start,
+                curves: Vec::new(),
+                closed: false,
+            }),
+            Curve::Close(..) => {
+                if let Some(last) = result.last_mut() {
+                    last.closed = true;
+                }
+            }
+            _ => {
+                match result.last_mut() {
+                    Some(last) if !last.closed => last.curves.push(curve),
+                    _ => result.push(Subpath {
+                        start: curve.start(),
+                        curves: vec![curve],
+                        closed: false,
+                    }),
+                };
+            }
+        }
+    }
+    result
+}
@@ -0,0 +1,215 @@
+//! Path data parsing.
+//!
+//! Follows the [SVG 1.1 path data grammar](https://www.w3.org/TR/SVG11/paths.html#PathDataBNF).
+
+use std::str::FromStr;
+
+use crate::{error::PathError, math::Number};
+
+use super::{Command, CommandData, PathData, PathSegment};
+
+struct Parser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(data: &'a str) -> Self {
+        Parser {
+            data: data.as_bytes(),
+            position: 0,
+        }
+    }
+
+    #[inline]
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r' | b'\x0C') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Skips whitespace and at most one comma. Returns `true` if a comma was
+    /// skipped.
+    fn skip_comma_whitespace(&mut self) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(b',') {
+            self.position += 1;
+            self.skip_whitespace();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn at_number(&self) -> bool {
+        matches!(self.peek(), Some(b'0'..=b'9' | b'.' | b'+' | b'-'))
+    }
+
+    fn skip_digits(&mut self) -> usize {
+        let start = self.position;
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn number(&mut self) -> Result<Number, PathError> {
+        let start = self.position;
+        if let Some(b'+' | b'-') = self.peek() {
+            self.position += 1;
+        }
+        let mut digits = self.skip_digits();
+        if self.peek() == Some(b'.') {
+            self.position += 1;
+            digits += self.skip_digits();
+        }
+        if digits == 0 {
+            return Err(PathError::InvalidNumber(start));
+        }
+
+        if let Some(b'e' | b'E') = self.peek() {
+            let mantissa_end = self.position;
+            self.position += 1;
+            if let Some(b'+' | b'-') = self.peek() {
+                self.position += 1;
+            }
+            if self.skip_digits() == 0 {
+                self.position = mantissa_end;
+            }
+        }
+
+        // SAFETY: only ASCII characters were consumed
+        let text = unsafe { std::str::from_utf8_unchecked(&self.data[start..self.position]) };
+        text.parse()
+            .map_err(|_| PathError::InvalidNumber(start))
+    }
+
+    fn flag(&mut self) -> Result<Number, PathError> {
+        let value = match self.peek() {
+            Some(b'0') => 0.0,
+            Some(b'1') => 1.0,
+            _ => return Err(PathError::InvalidNumber(self.position)),
+        };
+        self.position += 1;
+        Ok(value)
+    }
+
+    fn arguments(&mut self, command: Command) -> Result<[Number; 7], PathError> {
+        let mut arguments = [0.0; 7];
+        for (i, argument) in arguments
+            .iter_mut()
+            .take(command.argument_count())
+            .enumerate()
+        {
+            if i > 0 {
+                self.skip_comma_whitespace();
+            }
+            *argument = if command == Command::Elliptical && (i == 3 || i == 4) {
+                self.flag()?
+            } else {
+                self.number()?
+            };
+        }
+        Ok(arguments)
+    }
+
+    fn parse(&mut self, path: &mut PathData) -> Result<(), PathError> {
+        self.skip_whitespace();
+        while let Some(c) = self.peek() {
+            let command_position = self.position;
+            let (mut command, relative) =
+                Command::from_char(c as char).ok_or_else(|| self.unexpected())?;
+            self.position += 1;
+            self.skip_whitespace();
+
+            if command == Command::Close {
+                path.segments.push(PathSegment {
+                    relative,
+                    data: CommandData::Close([]),
+                });
+                continue;
+            }
+
+            if !self.at_number() {
+                return Err(PathError::MissingArguments {
+                    position: command_position,
+                    command,
+                });
+            }
+
+            loop {
+                let arguments = self.arguments(command)?;
+                path.segments.push(PathSegment {
+                    relative,
+                    data: command_data(command, arguments),
+                });
+
+                let comma = self.skip_comma_whitespace();
+                if !self.at_number() {
+                    if comma {
+                        return Err(self.unexpected());
+                    }
+                    break;
+                }
+
+                // additional coordinate pairs after a move are implicit lines
+                if command == Command::Move {
+                    command = Command::Line;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn unexpected(&self) -> PathError {
+        // SAFETY: position is always at a char boundary as only ASCII
+        // characters are consumed
+        let rest = unsafe { std::str::from_utf8_unchecked(&self.data[self.position..]) };
+        match rest.chars().next() {
+            Some(found) => PathError::UnexpectedCharacter {
+                position: self.position,
+                found,
+            },
+            None => PathError::InvalidNumber(self.position),
+        }
+    }
+}
+
+fn command_data(command: Command, a: [Number; 7]) -> CommandData {
+    match command {
+        Command::Move => CommandData::Move([a[0], a[1]]),
+        Command::Line => CommandData::Line([a[0], a[1]]),
+        Command::Horizontal => CommandData::Horizontal([a[0]]),
+        Command::Vertical => CommandData::Vertical([a[0]]),
+        Command::Cubic => CommandData::Cubic([a[0], a[1], a[2], a[3], a[4], a[5]]),
+        Command::CubicSmooth => CommandData::CubicSmooth([a[0], a[1], a[2], a[3]]),
+        Command::Quadratic => CommandData::Quadratic([a[0], a[1], a[2], a[3]]),
+        Command::QuadraticSmooth => CommandData::QuadraticSmooth([a[0], a[1]]),
+        Command::Elliptical => CommandData::Elliptical(a),
+        Command::Close => CommandData::Close([]),
+    }
+}
+
+impl FromStr for PathData {
+    type Err = PathError;
+
+    /// Parses path data and [validates](PathData::validate) it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // every command letter starts at least one segment, only implicit
+        // repetitions aren't accounted for
+        let commands = s
+            .bytes()
+            .filter(|it| it.is_ascii_alphabetic() && !matches!(it, b'e' | b'E'))
+            .count();
+        let mut path = PathData::with_capacity(commands);
+        Parser::new(s).parse(&mut path)?;
+        path.validate()?;
+        Ok(path)
+    }
+}
+
+impl_from_str_conversions!(PathData);
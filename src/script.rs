@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use structuredvg_macros::BundleAttributes;
 
+use crate::common::{CoreAttributes, XLinkAttributes};
+
 /// Event attributes that can be specified on most
 /// [graphics elements](https://www.w3.org/TR/SVG11/intro.html#TermGraphicsElement)
 /// and
@@ -13,6 +15,7 @@ use structuredvg_macros::BundleAttributes;
 /// - [SVG 1.1: Graphics Events](https://www.w3.org/TR/SVG11/script.html#GraphicsEvents)
 /// - [SVG 1.1: SVG Events](https://www.w3.org/TR/SVG11/interact.html#SVGEvents)
 #[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphicalEvents<'a> {
     /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/interact.html#FocusInEvent)
     #[xml_attribute]
@@ -42,3 +45,72 @@ pub struct GraphicalEvents<'a> {
     #[xml_attribute]
     pub onmouseout: Option<Cow<'a, str>>,
 }
+
+/// Event attributes that can be specified on animation elements.
+///
+/// Values of all of these are [`<anything>`](https://www.w3.org/TR/SVG11/types.html#DataTypeAnything)
+/// represented as `Cow<'_, str>`.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/script.html#AnimationEvents)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnimationEvents<'a> {
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#BeginEvent)
+    #[xml_attribute]
+    pub onbegin: Option<Cow<'a, str>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#EndEvent)
+    #[xml_attribute]
+    pub onend: Option<Cow<'a, str>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/animate.html#RepeatEvent)
+    #[xml_attribute]
+    pub onrepeat: Option<Cow<'a, str>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/interact.html#LoadEvent)
+    #[xml_attribute]
+    pub onload: Option<Cow<'a, str>>,
+}
+
+/// Script which is executed by the user agent.
+///
+/// Content is written inside a CDATA section for XML documents.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/script.html#ScriptElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementScript<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// XLink attributes referencing an external script.
+    #[xml_attribute_bundle]
+    pub xlink: Box<XLinkAttributes<'a>>,
+
+    /// Media type of the scripting language.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/script.html#ScriptElementTypeAttribute)
+    #[xml_attribute {
+        name: "type",
+    }]
+    pub content_type: Option<Cow<'a, str>>,
+
+    pub content: Cow<'a, str>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementScript<'_> {
+    fn size_hint(&self) -> usize {
+        "<script><![CDATA[]]></script>".len() + self.content.len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<script")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        writer.write_all(b">")?;
+        crate::io::write_raw_text(writer, &self.content, settings)?;
+        writer.write_all(b"</script>")
+    }
+}
@@ -0,0 +1,501 @@
+use std::str::FromStr;
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    common::{ConditionalProcessing, CoreAttributes, PositionSize},
+    error::InvalidPoints,
+    math::{Bounded, Coordinate, NonNegativeLength, Number, Point, Rect, Vector},
+    script::GraphicalEvents,
+    style::{PaintingAttributes, ReferenceProperties},
+};
+
+/// Returns `coordinate` in user units, treating missing values as `0`.
+fn coordinate(coordinate: Option<Coordinate>) -> Option<Number> {
+    match coordinate {
+        Some(it) => it.to_user_units(),
+        None => Some(0.0),
+    }
+}
+
+/// List of points forming vertices of a `<polyline>` or `<polygon>`.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#PointsBNF)
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointList {
+    pub points: Vec<Point>,
+}
+
+impl PointList {
+    /// Constructs a list from `(x, y)` coordinate pairs.
+    pub fn from_slice(points: &[(Number, Number)]) -> Self {
+        PointList {
+            points: points.iter().map(|&(x, y)| Point::new(x, y)).collect(),
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, x: Number, y: Number) {
+        self.points.push(Point::new(x, y));
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Returns `(x, y)` coordinates of the point at `index`, or `None` if
+    /// it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<(Number, Number)> {
+        self.points.get(index).map(|it| (it.x, it.y))
+    }
+
+    /// Returns an iterator over `(x, y)` coordinates of points.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Number, Number)> + '_ {
+        self.points.iter().map(|it| (it.x, it.y))
+    }
+
+    /// Returns `true` if the last point is the same as the first one.
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 1 && self.points.first() == self.points.last()
+    }
+
+    /// Appends the first point to the end of the list so a `<polyline>` is
+    /// drawn as a closed outline, unless the list already ends with it.
+    ///
+    /// `<polygon>`s are implicitly closed and don't need the point to be
+    /// repeated.
+    pub fn close(&mut self) {
+        if self.points.len() > 1 && !self.is_closed() {
+            self.points.push(self.points[0]);
+        }
+    }
+}
+
+impl FromStr for PointList {
+    type Err = InvalidPoints;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let coordinates = s
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|it| !it.is_empty())
+            .map(str::parse::<Number>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| InvalidPoints(s.to_string()))?;
+        if coordinates.len() % 2 != 0 {
+            return Err(InvalidPoints(s.to_string()));
+        }
+
+        Ok(PointList {
+            points: coordinates
+                .chunks_exact(2)
+                .map(|it| Point::new(it[0], it[1]))
+                .collect(),
+        })
+    }
+}
+
+impl_from_str_conversions!(PointList);
+
+impl Bounded for PointList {
+    fn bounding_box(&self) -> Option<Rect> {
+        let (first, rest) = self.points.split_first()?;
+        Some(rest.iter().fold(Rect::from_points(*first, *first), |it, point| {
+            it.include(*point)
+        }))
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for PointList {
+    fn size_hint(&self) -> usize {
+        self.points.len() * 16
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        crate::io::write_wrapped(&self.points, b" ", writer, settings)
+    }
+}
+
+/// Rectangle, optionally with rounded corners.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#RectElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementRect<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    /// Position and size of the rectangle.
+    #[xml_attribute_bundle]
+    pub position_size: Box<PositionSize>,
+
+    /// Horizontal radius of rounded corners.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#RectElementRXAttribute)
+    #[xml_attribute]
+    pub rx: Option<NonNegativeLength>,
+
+    /// Vertical radius of rounded corners.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#RectElementRYAttribute)
+    #[xml_attribute]
+    pub ry: Option<NonNegativeLength>,
+}
+
+impl_paint_shorthands!(ElementRect);
+
+impl Bounded for ElementRect<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.position_size.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementRect<'_> {
+    fn size_hint(&self) -> usize {
+        "<rect/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<rect")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "rect", settings)
+    }
+}
+
+/// Circle based on a center point and a radius.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#CircleElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementCircle<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    #[xml_attribute]
+    pub cx: Option<Coordinate>,
+    #[xml_attribute]
+    pub cy: Option<Coordinate>,
+    #[xml_attribute]
+    pub r: Option<NonNegativeLength>,
+}
+
+impl_paint_shorthands!(ElementCircle);
+
+impl Bounded for ElementCircle<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        let center = Point::new(coordinate(self.cx)?, coordinate(self.cy)?);
+        let r = self.r?.to_user_units()?;
+        let radii = Vector::new(r, r);
+        Some(Rect::from_points(center - radii, center + radii))
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementCircle<'_> {
+    fn size_hint(&self) -> usize {
+        "<circle/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<circle")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "circle", settings)
+    }
+}
+
+/// Ellipse aligned with the axes of the current user coordinate system.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#EllipseElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementEllipse<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    #[xml_attribute]
+    pub cx: Option<Coordinate>,
+    #[xml_attribute]
+    pub cy: Option<Coordinate>,
+    #[xml_attribute]
+    pub rx: Option<NonNegativeLength>,
+    #[xml_attribute]
+    pub ry: Option<NonNegativeLength>,
+}
+
+impl_paint_shorthands!(ElementEllipse);
+
+impl Bounded for ElementEllipse<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        let center = Point::new(coordinate(self.cx)?, coordinate(self.cy)?);
+        let radii = Vector::new(self.rx?.to_user_units()?, self.ry?.to_user_units()?);
+        Some(Rect::from_points(center - radii, center + radii))
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementEllipse<'_> {
+    fn size_hint(&self) -> usize {
+        "<ellipse/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<ellipse")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "ellipse", settings)
+    }
+}
+
+/// Line segment between two points.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#LineElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementLine<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    #[xml_attribute]
+    pub x1: Option<Coordinate>,
+    #[xml_attribute]
+    pub y1: Option<Coordinate>,
+    #[xml_attribute]
+    pub x2: Option<Coordinate>,
+    #[xml_attribute]
+    pub y2: Option<Coordinate>,
+}
+
+impl_paint_shorthands!(ElementLine);
+
+impl Bounded for ElementLine<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        Some(Rect::from_points(
+            Point::new(coordinate(self.x1)?, coordinate(self.y1)?),
+            Point::new(coordinate(self.x2)?, coordinate(self.y2)?),
+        ))
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementLine<'_> {
+    fn size_hint(&self) -> usize {
+        "<line/>".len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<line")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "line", settings)
+    }
+}
+
+/// Set of connected straight line segments.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#PolylineElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementPolyline<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#PolylineElementPointsAttribute)
+    #[xml_attribute]
+    pub points: Option<PointList>,
+}
+
+impl_paint_shorthands!(ElementPolyline);
+
+impl Bounded for ElementPolyline<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.points.as_ref()?.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementPolyline<'_> {
+    fn size_hint(&self) -> usize {
+        "<polyline points=\"\"/>".len()
+            + self.points.as_ref().map(|it| it.size_hint()).unwrap_or_default()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<polyline")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "polyline", settings)
+    }
+}
+
+/// Closed shape consisting of a set of connected straight line segments.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#PolygonElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementPolygon<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Fill and stroke presentation attributes.
+    #[xml_attribute_bundle]
+    pub painting: Box<PaintingAttributes<'a>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/shapes.html#PolygonElementPointsAttribute)
+    #[xml_attribute]
+    pub points: Option<PointList>,
+}
+
+impl_paint_shorthands!(ElementPolygon);
+
+impl Bounded for ElementPolygon<'_> {
+    fn bounding_box(&self) -> Option<Rect> {
+        self.points.as_ref()?.bounding_box()
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementPolygon<'_> {
+    fn size_hint(&self) -> usize {
+        "<polygon points=\"\"/>".len()
+            + self.points.as_ref().map(|it| it.size_hint()).unwrap_or_default()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<polygon")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_empty_element_end(writer, "polygon", settings)
+    }
+}
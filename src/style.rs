@@ -1,7 +1,23 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use structuredvg_macros::BundleAttributes;
+
+use crate::{
+    common::CoreAttributes,
+    error::{DashError, InvalidColor, InvalidFuncIri, InvalidPaint, InvalidVar},
+    io::*,
+    math::{Length, Number, PositiveNumber},
+};
+
+mod named_colors;
 
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeclarationList<'a> {
+    /// Declarations in order of appearance.
+    ///
+    /// Prefer iterating and indexing the list itself, this field may become
+    /// private in the future.
     pub declarations: Vec<Declaration<'a>>,
 }
 
@@ -13,6 +29,200 @@ impl<'a> DeclarationList<'a> {
             value: value.into(),
         })
     }
+
+    /// Appends a custom property (CSS variable) declaration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` doesn't start with `--`.
+    pub fn push_custom(&mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
+        let name = name.into();
+        assert!(
+            name.starts_with("--"),
+            "custom property name '{name}' should start with '--'"
+        );
+        self.declarations.push(Declaration::Custom {
+            name,
+            value: value.into(),
+        })
+    }
+
+    /// Applies declarations of `other` on top of this list.
+    ///
+    /// Properties of `other` replace the ones with the same name in this list,
+    /// unless only the existing one is `!important`. Properties that aren't
+    /// present yet are appended, while comments are skipped.
+    pub fn merge(&mut self, other: &DeclarationList<'a>) {
+        for declaration in &other.declarations {
+            let Some(name) = declaration.name() else {
+                continue;
+            };
+            // last declaration is the one in effect
+            let existing = self
+                .declarations
+                .iter_mut()
+                .rev()
+                .find(|it| it.name().is_some_and(|it| names_match(it, name)));
+            match existing {
+                Some(existing) => {
+                    if declaration.is_important() || !existing.is_important() {
+                        *existing = declaration.clone();
+                    }
+                }
+                None => self.declarations.push(declaration.clone()),
+            }
+        }
+    }
+
+    /// Returns an iterator over declarations in order of appearance.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Declaration<'a>> {
+        self.declarations.iter()
+    }
+
+    /// Returns an iterator that allows modifying declarations.
+    #[inline]
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Declaration<'a>> {
+        self.declarations.iter_mut()
+    }
+}
+
+impl<'a> std::ops::Index<usize> for DeclarationList<'a> {
+    type Output = Declaration<'a>;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Declaration<'a> {
+        &self.declarations[index]
+    }
+}
+
+impl<'a> std::ops::IndexMut<usize> for DeclarationList<'a> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Declaration<'a> {
+        &mut self.declarations[index]
+    }
+}
+
+impl<'a> IntoIterator for DeclarationList<'a> {
+    type Item = Declaration<'a>;
+    type IntoIter = std::vec::IntoIter<Declaration<'a>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.declarations.into_iter()
+    }
+}
+
+impl<'l, 'a> IntoIterator for &'l DeclarationList<'a> {
+    type Item = &'l Declaration<'a>;
+    type IntoIter = std::slice::Iter<'l, Declaration<'a>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.declarations.iter()
+    }
+}
+
+impl<'l, 'a> IntoIterator for &'l mut DeclarationList<'a> {
+    type Item = &'l mut Declaration<'a>;
+    type IntoIter = std::slice::IterMut<'l, Declaration<'a>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.declarations.iter_mut()
+    }
+}
+
+/// Compares property names, ignoring ASCII case unless they're custom
+/// properties.
+fn names_match(a: &str, b: &str) -> bool {
+    if a.starts_with("--") {
+        a == b
+    } else {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+impl FromStr for DeclarationList<'_> {
+    type Err = std::convert::Infallible;
+
+    /// Parses a CSS declaration block, such as the value of a `style`
+    /// attribute.
+    ///
+    /// Comments between declarations are preserved as
+    /// [`Declaration::Comment`]s. Like in CSS, malformed declarations without
+    /// a name or a `:` are dropped instead of producing an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = DeclarationList::default();
+        let mut rest = s;
+        loop {
+            rest = rest.trim_start();
+            if let Some(comment) = rest.strip_prefix("/*") {
+                let (text, after) = comment.split_once("*/").unwrap_or((comment, ""));
+                result
+                    .declarations
+                    .push(Declaration::Comment(Cow::Owned(text.trim().to_string())));
+                rest = after;
+                continue;
+            }
+            if rest.is_empty() {
+                break;
+            }
+
+            let end = declaration_end(rest);
+            if let Some((name, value)) = rest[..end].split_once(':') {
+                let name = name.trim();
+                let value = value.trim().to_string();
+                if name.starts_with("--") {
+                    result.push_custom(name.to_string(), value);
+                } else if !name.is_empty() {
+                    result.push_property(name.to_string(), value);
+                }
+            }
+            rest = rest.get(end + 1..).unwrap_or_default();
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "read")]
+impl Readable for DeclarationList<'_> {
+    #[inline]
+    fn read(value: &str) -> Option<Self> {
+        value.parse().ok()
+    }
+}
+
+/// Returns byte index of the `;` terminating the first declaration in `s`, or
+/// length of `s` if it's the last one.
+///
+/// Semicolons within strings, parentheses and comments don't terminate a
+/// declaration.
+fn declaration_end(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        match (quote, bytes[i]) {
+            (Some(_), b'\\') => i += 1,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, b'"' | b'\'') => quote = Some(bytes[i]),
+            (None, b'(') => depth += 1,
+            (None, b')') => depth = depth.saturating_sub(1),
+            (None, b'/') if bytes.get(i + 1) == Some(&b'*') => {
+                i = match s[i + 2..].find("*/") {
+                    Some(end) => i + 2 + end + 1,
+                    None => bytes.len(),
+                };
+            }
+            (None, b';') if depth == 0 => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
 }
 
 #[cfg(feature = "write")]
@@ -22,29 +232,21 @@ impl crate::io::Writable for DeclarationList<'_> {
         writer: &mut W,
         settings: &crate::io::WriteSettings,
     ) -> std::io::Result<()> {
-        let non_empty: Vec<_> = self
-            .declarations
-            .iter()
-            .filter(|it| !it.is_empty())
-            .collect();
-        if non_empty.len() > 0 {
-            for declaration in non_empty.iter().take(self.declarations.len() - 1) {
-                if declaration.is_empty() {
-                    continue;
-                }
-                declaration.write_to(writer, settings)?;
-                writer.write(b";")?;
+        // properties are only terminated if followed by another declaration
+        let mut unterminated = false;
+        for declaration in self.declarations.iter().filter(|it| !it.is_empty()) {
+            if unterminated {
+                writer.write_all(b";")?;
             }
-            self.declarations
-                .last()
-                .unwrap()
-                .write_to(writer, settings)?;
+            declaration.write_to(writer, settings)?;
+            unterminated = !matches!(declaration, Declaration::Comment(_));
         }
         Ok(())
     }
 }
 
 #[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Declaration<'a> {
     #[default]
     Empty,
@@ -52,12 +254,46 @@ pub enum Declaration<'a> {
         name: Cow<'a, str>,
         value: Cow<'a, str>,
     },
+    /// Custom property (CSS variable) whose `name` starts with `--`.
+    ///
+    /// [CSS documentation](https://www.w3.org/TR/css-variables-1/#defining-variables)
+    Custom {
+        name: Cow<'a, str>,
+        value: Cow<'a, str>,
+    },
+    /// Comment text, without the surrounding `/*` and `*/`.
+    ///
+    /// Text containing `*/` is written as consecutive comments split between
+    /// `*` and `/`.
+    Comment(Cow<'a, str>),
 }
 
 impl<'a> Declaration<'a> {
     pub fn is_empty(&self) -> bool {
         matches!(self, Self::Empty)
     }
+
+    /// Returns the name of declared property, or `None` if this isn't a
+    /// property declaration.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Property { name, .. } | Self::Custom { name, .. } => Some(name),
+            Self::Empty | Self::Comment(_) => None,
+        }
+    }
+
+    /// Returns `true` if this is a property declaration whose value is marked
+    /// `!important`.
+    pub fn is_important(&self) -> bool {
+        let value = match self {
+            Self::Property { value, .. } | Self::Custom { value, .. } => value,
+            Self::Empty | Self::Comment(_) => return false,
+        };
+        value
+            .trim_end()
+            .rsplit_once('!')
+            .is_some_and(|(_, flag)| flag.trim().eq_ignore_ascii_case("important"))
+    }
 }
 
 #[cfg(feature = "write")]
@@ -65,16 +301,1109 @@ impl crate::io::Writable for Declaration<'_> {
     fn write_to<W: std::io::Write>(
         &self,
         writer: &mut W,
-        _settings: &crate::io::WriteSettings,
+        settings: &crate::io::WriteSettings,
     ) -> std::io::Result<()> {
+        // declarations are written into the `style` attribute, so they're
+        // escaped like any other attribute value
         match self {
             Self::Empty => Ok(()),
-            Self::Property { name, value } => {
-                writer.write(name.as_bytes())?;
-                writer.write(b":")?;
-                writer.write(value.as_bytes())?;
+            Self::Property { name, value } | Self::Custom { name, value } => {
+                write_str_escaped(writer, name, settings)?;
+                writer.write_all(b":")?;
+                write_str_escaped(writer, value, settings)
+            }
+            Self::Comment(text) => {
+                writer.write_all(b"/* ")?;
+                // comment can't contain its terminator so it's split in two
+                // comments between `*` and `/`
+                let mut parts = text.split("*/");
+                if let Some(first) = parts.next() {
+                    write_str_escaped(writer, first, settings)?;
+                }
+                for part in parts {
+                    writer.write_all(b"* *//* /")?;
+                    write_str_escaped(writer, part, settings)?;
+                }
+                writer.write_all(b" */")
+            }
+        }
+    }
+}
+
+/// Reference to a custom property (CSS variable) in the form of
+/// `var(--name)` or `var(--name, fallback)`, usable as a property value.
+///
+/// [CSS documentation](https://www.w3.org/TR/css-variables-1/#using-variables)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Var<'a> {
+    /// Name of the referenced custom property, including the `--` prefix.
+    pub name: Cow<'a, str>,
+    /// Value used if the custom property isn't defined.
+    pub fallback: Option<Cow<'a, str>>,
+}
+
+impl<'a> Var<'a> {
+    #[inline]
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Self {
+        Var {
+            name: name.into(),
+            fallback: None,
+        }
+    }
+
+    #[inline]
+    pub fn with_fallback(mut self, fallback: impl Into<Cow<'a, str>>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+}
+
+impl Display for Var<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.fallback {
+            Some(fallback) => write!(f, "var({}, {})", self.name, fallback),
+            None => write!(f, "var({})", self.name),
+        }
+    }
+}
+
+impl FromStr for Var<'_> {
+    type Err = InvalidVar;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let arguments = s
+            .trim()
+            .strip_prefix("var(")
+            .and_then(|it| it.strip_suffix(')'))
+            .ok_or_else(|| InvalidVar(s.to_string()))?;
+        let (name, fallback) = match arguments.split_once(',') {
+            Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+            None => (arguments.trim(), None),
+        };
+        if !name.starts_with("--") || name.len() == 2 {
+            return Err(InvalidVar(s.to_string()));
+        }
+
+        Ok(Var {
+            name: Cow::Owned(name.to_string()),
+            fallback: fallback.map(|it| Cow::Owned(it.to_string())),
+        })
+    }
+}
+
+impl_from_str_conversions!(Var<'a>);
+impl_serde_string!(Var<'a>);
+
+impl FromStringUnsafe for Var<'_> {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_else(|_| Var::new(value))
+    }
+}
+
+impl AttributeValue for Var<'_> {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"var(")?;
+        write_str_escaped(writer, &self.name, settings)?;
+        if let Some(fallback) = &self.fallback {
+            writer.write_all(b", ")?;
+            write_str_escaped(writer, fallback, settings)?;
+        }
+        writer.write_all(b")")
+    }
+}
+
+impl_writable_attribute_value!(Var<'_>);
+
+/// Wraps presentation property values which additionally accept the `inherit`
+/// keyword.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/styling.html#Inheritance)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inheritable<T> {
+    /// Value is the same as the computed value of the property on the parent
+    /// element.
+    Inherit,
+    Value(T),
+}
+
+impl<T> Inheritable<T> {
+    /// Returns the contained value, or `None` if it's inherited.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Inheritable::Inherit => None,
+            Inheritable::Value(it) => Some(it),
+        }
+    }
+}
+
+impl<T: Default> Default for Inheritable<T> {
+    fn default() -> Self {
+        Inheritable::Value(T::default())
+    }
+}
+
+impl<T: AttributeValue> Display for Inheritable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Inheritable::Inherit => f.write_str("inherit"),
+            Inheritable::Value(value) => match value.as_str() {
+                Some(it) => f.write_str(it),
+                None => f.write_str(&value.to_string()),
+            },
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for Inheritable<T> {
+    type Err = T::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "inherit" {
+            Ok(Inheritable::Inherit)
+        } else {
+            T::from_str(s).map(Inheritable::Value)
+        }
+    }
+}
+
+#[cfg(feature = "read")]
+impl<T: Readable> Readable for Inheritable<T> {
+    fn read(value: &str) -> Option<Self> {
+        if value == "inherit" {
+            Some(Inheritable::Inherit)
+        } else {
+            T::read(value).map(Inheritable::Value)
+        }
+    }
+}
+
+impl<T: FromStringUnsafe> FromStringUnsafe for Inheritable<T> {
+    unsafe fn from(value: String) -> Self {
+        if value == "inherit" {
+            Inheritable::Inherit
+        } else {
+            Inheritable::Value(T::from(value))
+        }
+    }
+}
+
+impl<T: AttributeValue + FromStr> AttributeValue for Inheritable<T> {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Inheritable::Inherit => writer.write_all(b"inherit"),
+            Inheritable::Value(value) => value.write_to(writer, settings),
+        }
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Inheritable::Inherit => Some("inherit"),
+            Inheritable::Value(value) => value.as_str(),
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl<T: AttributeValue + FromStr> crate::io::Writable for Inheritable<T> {
+    #[inline]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        AttributeValue::write_to(self, writer, settings)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> usize {
+        self.as_str().map(str::len).unwrap_or_default()
+    }
+
+    #[inline]
+    fn as_attribute_str(&self) -> Option<&str> {
+        self.as_str()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: AttributeValue> serde::Serialize for Inheritable<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: FromStr> serde::Deserialize<'de> for Inheritable<T>
+where
+    T::Err: Display,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = <Cow<'de, str>>::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+keyword_enum! {
+    /// Shape at the end of open subpaths when they're stroked.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeLinecapProperty)
+    pub enum LineCap {
+        #[default]
+        Butt => "butt",
+        Round => "round",
+        Square => "square",
+    }
+}
+
+keyword_enum! {
+    /// Shape at the corners of paths when they're stroked.
+    ///
+    /// `arcs` and `miter-clip` values are part of SVG 2 specification.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeLinejoinProperty)
+    pub enum LineJoin {
+        #[default]
+        Miter => "miter",
+        Round => "round",
+        Bevel => "bevel",
+        Arcs => "arcs",
+        MiterClip => "miter-clip",
+    }
+}
+
+keyword_enum! {
+    /// Algorithm used to determine which parts of the canvas are inside a shape.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#FillRuleProperty)
+    pub enum FillRule {
+        #[default]
+        NonZero => "nonzero",
+        EvenOdd => "evenodd",
+    }
+}
+
+/// Opacity value clamped to `[0, 1]` range, where `0` is fully transparent.
+///
+/// Used by `opacity`, `fill-opacity` and `stroke-opacity` properties.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#OpacityProperty)
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Opacity(Number);
+
+impl Opacity {
+    pub const TRANSPARENT: Opacity = Opacity(0.0);
+    pub const OPAQUE: Opacity = Opacity(1.0);
+
+    /// Constructs a new opacity, clamping `value` to `[0, 1]` range.
+    ///
+    /// `NaN` is treated as fully transparent.
+    pub fn new(value: Number) -> Self {
+        if value.is_nan() {
+            Opacity::TRANSPARENT
+        } else {
+            Opacity(value.clamp(0.0, 1.0))
+        }
+    }
+
+    #[inline]
+    pub fn to_inner(&self) -> Number {
+        self.0
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Number {
+        self.0
+    }
+}
+
+impl Default for Opacity {
+    fn default() -> Self {
+        Opacity::OPAQUE
+    }
+}
+
+impl Display for Opacity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Opacity {
+    type Err = std::num::ParseFloatError;
+
+    /// Parses an opacity value, which may also be written as a percentage.
+    ///
+    /// Values outside of `[0, 1]` range are clamped.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_suffix('%') {
+            Some(percentage) => Ok(Opacity::new(percentage.parse::<Number>()? / 100.0)),
+            None => Ok(Opacity::new(s.parse()?)),
+        }
+    }
+}
+
+impl_from_str_conversions!(Opacity);
+impl_serde_string!(Opacity);
+
+impl FromStringUnsafe for Opacity {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for Opacity {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write_number(writer, self.0, settings)
+    }
+}
+
+impl_writable_attribute_value!(Opacity);
+
+/// Reference to another element in the form of `url(<IRI>)`, or `none`.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/types.html#DataTypeFuncIRI)
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum FuncIRIOrNone<'a> {
+    #[default]
+    None,
+    /// IRI of the referenced element, without the `url()` wrapper.
+    Url(Cow<'a, str>),
+}
+
+impl<'a> FuncIRIOrNone<'a> {
+    /// Constructs a reference to an element in the same document with `id`.
+    pub fn reference(id: &str) -> Self {
+        FuncIRIOrNone::Url(Cow::Owned(format!("#{}", id)))
+    }
+
+    /// Returns the referenced IRI, or `None` if this value is `none`.
+    pub fn iri(&self) -> Option<&str> {
+        match self {
+            FuncIRIOrNone::None => None,
+            FuncIRIOrNone::Url(iri) => Some(iri),
+        }
+    }
+}
+
+impl Display for FuncIRIOrNone<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FuncIRIOrNone::None => f.write_str("none"),
+            FuncIRIOrNone::Url(iri) => write!(f, "url({})", iri),
+        }
+    }
+}
+
+impl FromStr for FuncIRIOrNone<'_> {
+    type Err = InvalidFuncIri;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if value == "none" {
+            return Ok(FuncIRIOrNone::None);
+        }
+
+        let iri = value
+            .strip_prefix("url(")
+            .and_then(|it| it.strip_suffix(')'))
+            .map(str::trim)
+            .ok_or_else(|| InvalidFuncIri(s.to_string()))?;
+        // CSS allows quoted URLs
+        let iri = ['"', '\'']
+            .into_iter()
+            .find_map(|quote| iri.strip_prefix(quote)?.strip_suffix(quote))
+            .unwrap_or(iri);
+        if iri.is_empty() {
+            return Err(InvalidFuncIri(s.to_string()));
+        }
+
+        Ok(FuncIRIOrNone::Url(Cow::Owned(iri.to_string())))
+    }
+}
+
+impl_from_str_conversions!(FuncIRIOrNone<'a>);
+impl_serde_string!(FuncIRIOrNone<'a>);
+
+impl FromStringUnsafe for FuncIRIOrNone<'_> {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for FuncIRIOrNone<'_> {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            FuncIRIOrNone::None => writer.write_all(b"none"),
+            FuncIRIOrNone::Url(iri) => {
+                writer.write_all(b"url(")?;
+                write_str_escaped(writer, iri, settings)?;
+                writer.write_all(b")")
+            }
+        }
+    }
+}
+
+impl_writable_attribute_value!(FuncIRIOrNone<'_>);
+
+/// sRGB color with an alpha channel.
+///
+/// Parsed from hexadecimal notation (`#rgb`, `#rgba`, `#rrggbb` and
+/// `#rrggbbaa`), `rgb()`, `rgba()`, `hsl()` and `hsla()` functions, named
+/// colors such as `red` and the `transparent` keyword. Out-of-range channel
+/// values are clamped.
+///
+/// Opaque colors are written in the shortest hexadecimal notation, while
+/// colors that aren't opaque are written with the `rgba()` function, as
+/// hexadecimal notation with alpha isn't supported by SVG 1.1 renderers.
+///
+/// [CSS Color documentation](https://www.w3.org/TR/css-color-4/)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Color with 8-bit red, green, blue and alpha channels, where alpha of
+    /// `255` is fully opaque.
+    Rgba { r: u8, g: u8, b: u8, a: u8 },
+}
+
+impl Color {
+    /// Constructs an opaque color.
+    #[inline]
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color::Rgba { r, g, b, a: 255 }
+    }
+
+    #[inline]
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color::Rgba { r, g, b, a }
+    }
+
+    /// Constructs a color from `hue` in degrees, and `saturation`,
+    /// `lightness` and `alpha` in `[0, 1]` range.
+    ///
+    /// Values outside of their ranges are clamped, while `hue` wraps around.
+    pub fn hsla(hue: Number, saturation: Number, lightness: Number, alpha: Number) -> Self {
+        let hue = hue.rem_euclid(360.0) / 360.0;
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+
+        let q = if lightness < 0.5 {
+            lightness * (1.0 + saturation)
+        } else {
+            lightness + saturation - lightness * saturation
+        };
+        let p = 2.0 * lightness - q;
+        let channel = |t: Number| {
+            let t = t.rem_euclid(1.0);
+            let value = if t < 1.0 / 6.0 {
+                p + (q - p) * 6.0 * t
+            } else if t < 1.0 / 2.0 {
+                q
+            } else if t < 2.0 / 3.0 {
+                p + (q - p) * (2.0 / 3.0 - t) * 6.0
+            } else {
+                p
+            };
+            unit_to_byte(value)
+        };
+
+        Color::Rgba {
+            r: channel(hue + 1.0 / 3.0),
+            g: channel(hue),
+            b: channel(hue - 1.0 / 3.0),
+            a: unit_to_byte(alpha),
+        }
+    }
+
+    /// Returns the alpha channel, where `255` is fully opaque.
+    #[inline]
+    pub const fn alpha(&self) -> u8 {
+        let Color::Rgba { a, .. } = *self;
+        a
+    }
+
+    #[inline]
+    pub const fn is_opaque(&self) -> bool {
+        self.alpha() == 255
+    }
+}
+
+/// Converts a value in `[0, 1]` range into a channel byte, clamping it.
+#[inline]
+fn unit_to_byte(value: Number) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Parses a hexadecimal color without the leading `#`.
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|it| it.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok();
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    match hex.len() {
+        3 | 4 => Some(Color::Rgba {
+            r: digit(0)? * 17,
+            g: digit(1)? * 17,
+            b: digit(2)? * 17,
+            a: if hex.len() == 4 { digit(3)? * 17 } else { 255 },
+        }),
+        6 | 8 => Some(Color::Rgba {
+            r: byte(0)?,
+            g: byte(2)?,
+            b: byte(4)?,
+            a: if hex.len() == 8 { byte(6)? } else { 255 },
+        }),
+        _ => None,
+    }
+}
+
+/// Parses a finite number, or a percentage of it if `value` ends with `%`.
+fn parse_color_number(value: &str, percentage_of: Number) -> Option<Number> {
+    let result = match value.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<Number>().ok()? / 100.0 * percentage_of,
+        None => value.parse::<Number>().ok()?,
+    };
+    result.is_finite().then_some(result)
+}
+
+impl Default for Color {
+    /// Opaque black.
+    #[inline]
+    fn default() -> Self {
+        Color::rgb(0, 0, 0)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Color::Rgba { r, g, b, a } = *self;
+        if a != 255 {
+            // `#rgba` and `#rrggbbaa` would be shorter, but SVG 1.1 renderers
+            // don't support hexadecimal notation with alpha, so `rgba()` is
+            // the most compact form they understand
+
+            // shortest alpha value that's parsed back into the same channel
+            let alpha = (1..=3)
+                .map(|precision| format!("{:.*}", precision, a as Number / 255.0))
+                .find(|it| it.parse().map(unit_to_byte) == Ok(a))
+                .unwrap_or_else(|| format!("{:.4}", a as Number / 255.0));
+            let alpha = alpha.trim_end_matches('0').trim_end_matches('.');
+            return write!(f, "rgba({},{},{},{})", r, g, b, alpha);
+        }
+
+        // short notation repeats each digit, e.g. `#f80` is `#ff8800`
+        if [r, g, b].iter().all(|it| it % 17 == 0) {
+            write!(f, "#{:x}{:x}{:x}", r / 17, g / 17, b / 17)
+        } else {
+            write!(f, "#{:02x}{:02x}{:02x}", r, g, b)
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = InvalidColor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidColor(s.to_string());
+        let value = s.trim();
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex_color(hex).ok_or_else(invalid);
+        }
+        if value.eq_ignore_ascii_case("transparent") {
+            return Ok(Color::rgba(0, 0, 0, 0));
+        }
+        if let Some(color) = named_colors::named_color(value) {
+            return Ok(color);
+        }
+
+        let (name, args) = value
+            .strip_suffix(')')
+            .and_then(|it| it.split_once('('))
+            .ok_or_else(invalid)?;
+        // both legacy `rgb(r, g, b, a)` and modern `rgb(r g b / a)` syntax
+        let args: Vec<&str> = args
+            .split(|it: char| it == ',' || it == '/' || it.is_whitespace())
+            .filter(|it| !it.is_empty())
+            .collect();
+        let (channels, alpha) = match args.as_slice() {
+            [x, y, z] => ([*x, *y, *z], 1.0),
+            [x, y, z, alpha] => (
+                [*x, *y, *z],
+                parse_color_number(alpha, 1.0).ok_or_else(invalid)?,
+            ),
+            _ => return Err(invalid()),
+        };
+
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => {
+                let mut rgb = [0; 3];
+                for (channel, value) in rgb.iter_mut().zip(channels) {
+                    let value = parse_color_number(value, 255.0).ok_or_else(invalid)?;
+                    *channel = value.clamp(0.0, 255.0).round() as u8;
+                }
+                let [r, g, b] = rgb;
+                Ok(Color::rgba(r, g, b, unit_to_byte(alpha)))
+            }
+            "hsl" | "hsla" => {
+                let [hue, saturation, lightness] = channels;
+                let hue = hue.strip_suffix("deg").unwrap_or(hue);
+                let hue = hue
+                    .parse::<Number>()
+                    .ok()
+                    .filter(|it| it.is_finite())
+                    .ok_or_else(invalid)?;
+                let percentage = |value: &str| {
+                    value
+                        .ends_with('%')
+                        .then(|| parse_color_number(value, 1.0))
+                        .flatten()
+                        .ok_or_else(invalid)
+                };
+                Ok(Color::hsla(
+                    hue,
+                    percentage(saturation)?,
+                    percentage(lightness)?,
+                    alpha,
+                ))
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+impl_from_str_conversions!(Color);
+impl_serde_string!(Color);
+
+impl FromStringUnsafe for Color {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for Color {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        // alpha is written with the precision needed to round-trip the
+        // channel, so number settings are ignored
+        write!(writer, "{}", self)
+    }
+}
+
+impl_writable_attribute_value!(Color);
+
+/// Value of `fill` and `stroke` properties.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#SpecifyingPaint)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Paint<'a> {
+    /// Nothing is painted.
+    None,
+    /// Value of the `color` property is used.
+    CurrentColor,
+    /// CSS color, such as `red` or `#ff8000`.
+    ///
+    /// Colors are stored and written as-is, they're not validated.
+    Color(Cow<'a, str>),
+    /// Reference to a paint server element, such as a gradient or a pattern.
+    Server {
+        /// IRI of the referenced element, without the `url()` wrapper.
+        iri: Cow<'a, str>,
+        /// Paint used if the reference can't be resolved, written as-is.
+        fallback: Option<Cow<'a, str>>,
+    },
+}
+
+impl<'a> Paint<'a> {
+    #[inline]
+    pub fn color(color: impl Into<Cow<'a, str>>) -> Self {
+        Paint::Color(color.into())
+    }
+
+    /// Parses the color of [`Paint::Color`], or returns `None` for other
+    /// paints.
+    pub fn parse_color(&self) -> Option<Result<Color, InvalidColor>> {
+        match self {
+            Paint::Color(color) => Some(color.parse()),
+            _ => None,
+        }
+    }
+
+    /// Constructs a reference to a paint server in the same document with
+    /// `id`.
+    pub fn reference(id: &str) -> Self {
+        Paint::Server {
+            iri: Cow::Owned(format!("#{}", id)),
+            fallback: None,
+        }
+    }
+}
+
+impl From<Color> for Paint<'_> {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Paint::Color(Cow::Owned(color.to_string()))
+    }
+}
+
+impl Display for Paint<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Paint::None => f.write_str("none"),
+            Paint::CurrentColor => f.write_str("currentColor"),
+            Paint::Color(color) => f.write_str(color),
+            Paint::Server { iri, fallback } => {
+                write!(f, "url({})", iri)?;
+                if let Some(fallback) = fallback {
+                    write!(f, " {}", fallback)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl FromStr for Paint<'_> {
+    type Err = InvalidPaint;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        if value.is_empty() {
+            return Err(InvalidPaint(s.to_string()));
+        }
+        if value == "none" {
+            return Ok(Paint::None);
+        }
+        if value.eq_ignore_ascii_case("currentColor") {
+            return Ok(Paint::CurrentColor);
+        }
+        if !value.starts_with("url(") {
+            return Ok(Paint::Color(Cow::Owned(value.to_string())));
+        }
+
+        let end = value.find(')').ok_or_else(|| InvalidPaint(s.to_string()))?;
+        let iri = match value[..=end].parse() {
+            Ok(FuncIRIOrNone::Url(iri)) => iri,
+            _ => return Err(InvalidPaint(s.to_string())),
+        };
+        let fallback = value[end + 1..].trim();
+        Ok(Paint::Server {
+            iri,
+            fallback: (!fallback.is_empty()).then(|| Cow::Owned(fallback.to_string())),
+        })
+    }
+}
+
+impl_from_str_conversions!(Paint<'a>);
+impl_serde_string!(Paint<'a>);
+
+impl FromStringUnsafe for Paint<'_> {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or(Paint::None)
+    }
+}
+
+impl AttributeValue for Paint<'_> {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        match self {
+            Paint::None => writer.write_all(b"none"),
+            Paint::CurrentColor => writer.write_all(b"currentColor"),
+            Paint::Color(color) => write_str_escaped(writer, color, settings),
+            Paint::Server { iri, fallback } => {
+                writer.write_all(b"url(")?;
+                write_str_escaped(writer, iri, settings)?;
+                writer.write_all(b")")?;
+                if let Some(fallback) = fallback {
+                    writer.write_all(b" ")?;
+                    write_str_escaped(writer, fallback, settings)?;
+                }
                 Ok(())
             }
         }
     }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            Paint::None => Some("none"),
+            Paint::CurrentColor => Some("currentColor"),
+            Paint::Color(color) => Some(color.as_ref()),
+            Paint::Server { .. } => None,
+        }
+    }
+}
+
+impl_writable_attribute_value!(Paint<'_>);
+
+/// Presentation attributes referencing clipping paths, masks and filters
+/// applied to an element.
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml_attribute_bundle { rename_all: "kebab-case" }]
+pub struct ReferenceProperties<'a> {
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#ClipPathProperty)
+    #[xml_attribute]
+    pub clip_path: Option<Inheritable<FuncIRIOrNone<'a>>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/masking.html#MaskProperty)
+    #[xml_attribute]
+    pub mask: Option<Inheritable<FuncIRIOrNone<'a>>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/filters.html#FilterProperty)
+    #[xml_attribute]
+    pub filter: Option<Inheritable<FuncIRIOrNone<'a>>>,
+}
+
+/// Pattern of dashes and gaps used to stroke paths, in user units.
+///
+/// An empty array is written as `none`. Per specification, an array of only
+/// zeros is rendered as a solid line too.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeDasharrayProperty)
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DashArray {
+    values: Vec<Number>,
+}
+
+impl DashArray {
+    /// Constructs a dash array from alternating dash and gap lengths.
+    ///
+    /// Returns an error if any of the `values` is negative or not finite.
+    pub fn new(values: Vec<Number>) -> Result<Self, DashError> {
+        for (i, value) in values.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(DashError::NotFinite(i));
+            }
+            if *value < 0.0 {
+                return Err(DashError::Negative(i));
+            }
+        }
+        Ok(DashArray { values })
+    }
+
+    #[inline]
+    pub fn values(&self) -> &[Number] {
+        &self.values
+    }
+
+    /// Returns `true` if this array doesn't produce any gaps and strokes are
+    /// drawn as solid lines.
+    pub fn is_solid(&self) -> bool {
+        self.values.iter().all(|it| *it == 0.0)
+    }
+}
+
+impl Display for DashArray {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.values.is_empty() {
+            return f.write_str("none");
+        }
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            value.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for DashArray {
+    type Err = DashError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "none" {
+            return Ok(DashArray::default());
+        }
+
+        let values = s
+            .split(|c: char| c == ',' || c.is_ascii_whitespace())
+            .filter(|it| !it.is_empty())
+            .enumerate()
+            .map(|(i, it)| it.parse().map_err(|_| DashError::InvalidNumber(i)))
+            .collect::<Result<Vec<Number>, _>>()?;
+        DashArray::new(values)
+    }
+}
+
+impl_from_str_conversions!(DashArray);
+impl_serde_string!(DashArray);
+
+impl FromStringUnsafe for DashArray {
+    unsafe fn from(value: String) -> Self {
+        value.parse().unwrap_or_default()
+    }
+}
+
+impl AttributeValue for DashArray {
+    #[cfg(feature = "write")]
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        if self.values.is_empty() {
+            return writer.write_all(b"none");
+        }
+        for (i, value) in self.values.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b",")?;
+            }
+            write_number(writer, *value, settings)?;
+        }
+        Ok(())
+    }
+}
+
+impl_writable_attribute_value!(DashArray);
+
+/// Presentation attributes that control how the outline of a shape is painted.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeProperties)
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml_attribute_bundle { rename_all: "kebab-case" }]
+pub struct StrokeAttributes<'a> {
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeProperty)
+    #[xml_attribute]
+    pub stroke: Option<Inheritable<Paint<'a>>>,
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#StrokeWidthProperty)
+    #[xml_attribute]
+    pub stroke_width: Option<Inheritable<Length>>,
+    #[xml_attribute]
+    pub stroke_linecap: Option<Inheritable<LineCap>>,
+    #[xml_attribute]
+    pub stroke_linejoin: Option<Inheritable<LineJoin>>,
+    #[xml_attribute]
+    pub stroke_miterlimit: Option<PositiveNumber>,
+    #[xml_attribute]
+    pub stroke_dasharray: Option<Inheritable<DashArray>>,
+    #[xml_attribute]
+    pub stroke_opacity: Option<Inheritable<Opacity>>,
+}
+
+/// Presentation attributes that control how the interior of a shape is
+/// painted.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#FillProperties)
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[xml_attribute_bundle { rename_all: "kebab-case" }]
+pub struct FillAttributes<'a> {
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html#FillProperty)
+    #[xml_attribute]
+    pub fill: Option<Inheritable<Paint<'a>>>,
+    #[xml_attribute]
+    pub fill_rule: Option<Inheritable<FillRule>>,
+    #[xml_attribute]
+    pub fill_opacity: Option<Inheritable<Opacity>>,
+}
+
+/// Presentation attributes that control how shapes are painted.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/painting.html)
+#[derive(Debug, Clone, Default, PartialEq, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PaintingAttributes<'a> {
+    #[xml_attribute_bundle]
+    pub fill: FillAttributes<'a>,
+    #[xml_attribute_bundle]
+    pub stroke: StrokeAttributes<'a>,
+}
+
+impl<'a> PaintingAttributes<'a> {
+    /// Sets the `fill` attribute to `paint`.
+    #[inline]
+    pub fn set_fill(&mut self, paint: Paint<'a>) {
+        self.fill.fill = Some(Inheritable::Value(paint));
+    }
+
+    /// Sets the `stroke` attribute to `paint`.
+    #[inline]
+    pub fn set_stroke(&mut self, paint: Paint<'a>) {
+        self.stroke.stroke = Some(Inheritable::Value(paint));
+    }
+
+    /// Sets the `stroke-width` attribute to `width`.
+    #[inline]
+    pub fn set_stroke_width(&mut self, width: impl Into<Length>) {
+        self.stroke.stroke_width = Some(Inheritable::Value(width.into()));
+    }
+}
+
+/// Style sheet embedded in the document.
+///
+/// Content is written inside a CDATA section for XML documents.
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/styling.html#StyleElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementStyle<'a> {
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Style sheet language, `text/css` is assumed when it isn't specified.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/styling.html#StyleElementTypeAttribute)
+    #[xml_attribute {
+        name: "type",
+    }]
+    pub content_type: Option<Cow<'a, str>>,
+
+    /// Comma separated list of media descriptors the style sheet applies to.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/styling.html#StyleElementMediaAttribute)
+    #[xml_attribute]
+    pub media: Option<Cow<'a, str>>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/styling.html#StyleElementTitleAttribute)
+    #[xml_attribute]
+    pub title: Option<Cow<'a, str>>,
+
+    pub content: Cow<'a, str>,
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementStyle<'_> {
+    fn size_hint(&self) -> usize {
+        "<style><![CDATA[]]></style>".len() + self.content.len()
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        writer.write_all(b"<style")?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        writer.write_all(b">")?;
+        crate::io::write_raw_text(writer, &self.content, settings)?;
+        writer.write_all(b"</style>")
+    }
 }
@@ -0,0 +1,112 @@
+use structuredvg_macros::BundleAttributes;
+
+#[cfg(feature = "read-xml")]
+mod read;
+#[cfg(feature = "read-xml")]
+pub use read::read_document;
+
+use crate::{
+    common::{ConditionalProcessing, CoreAttributes, PositionSize, PreserveAspectRatio, XmlBool},
+    container::Element,
+    math::{Bounded, ViewBox},
+    script::GraphicalEvents,
+    style::ReferenceProperties,
+};
+
+/// Document type declaration of SVG 1.1 documents, for use with
+/// [`WriteSettings::doctype`](crate::io::WriteSettings::doctype).
+pub const SVG_11_DOCTYPE: &str = r#"svg PUBLIC "-//W3C//DTD SVG 1.1//EN" "http://www.w3.org/Graphics/SVG/1.1/DTD/svg11.dtd""#;
+
+/// Namespace of SVG elements.
+pub const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// Root element of an SVG document fragment.
+///
+/// When written as the root element (at depth `0`), it's preceded by the
+/// prolog enabled in [`WriteSettings`](crate::io::WriteSettings).
+///
+/// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#SVGElement)
+#[derive(Debug, Clone, Default, BundleAttributes)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElementSvg<'a> {
+    /// Conditional processing attributes.
+    #[xml_attribute_bundle]
+    pub conditional_processing: Box<ConditionalProcessing<'a>>,
+
+    /// Core attributes.
+    #[xml_attribute_bundle]
+    pub core: Box<CoreAttributes<'a>>,
+
+    /// Graphical event attributes.
+    #[xml_attribute_bundle]
+    pub graphical_event: Box<GraphicalEvents<'a>>,
+
+    /// Clipping path, mask and filter references.
+    #[xml_attribute_bundle]
+    pub references: Box<ReferenceProperties<'a>>,
+
+    /// Position and size of the viewport.
+    ///
+    /// `x` and `y` have no effect on outermost `svg` elements.
+    #[xml_attribute_bundle]
+    pub position_size: Box<PositionSize>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#ViewBoxAttribute)
+    #[xml_attribute {
+        name: "viewBox",
+    }]
+    pub view_box: Option<ViewBox>,
+
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/coords.html#PreserveAspectRatioAttribute)
+    #[xml_attribute {
+        name: "preserveAspectRatio",
+    }]
+    pub preserve_aspect_ratio: Option<PreserveAspectRatio>,
+
+    /// Whether rendering should wait until external resources referenced by
+    /// the document are loaded.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/struct.html#ExternalResourcesRequiredAttribute)
+    #[xml_attribute {
+        name: "externalResourcesRequired",
+    }]
+    pub external_resources_required: Option<XmlBool>,
+
+    pub children: Vec<Element<'a>>,
+}
+
+impl ElementSvg<'_> {
+    /// Returns a `viewBox` fitting rendered children of this element, or
+    /// `None` if they have no bounds.
+    ///
+    /// Assign the result to [`view_box`](ElementSvg::view_box) to make the
+    /// content fill the viewport.
+    pub fn compute_view_box(&self) -> Option<ViewBox> {
+        self.children.bounding_box().map(ViewBox::from)
+    }
+}
+
+#[cfg(feature = "write")]
+impl crate::io::Writable for ElementSvg<'_> {
+    fn size_hint(&self) -> usize {
+        r#"<svg xmlns=""></svg>"#.len()
+            + SVG_NAMESPACE.len()
+            + crate::io::children_size_hint(&self.children)
+    }
+
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &crate::io::WriteSettings,
+    ) -> std::io::Result<()> {
+        if settings.depth == 0 {
+            settings.write_prolog(writer)?;
+        }
+        writer.write_all(b"<svg xmlns=")?;
+        settings.write_attribute_quote(writer)?;
+        writer.write_all(SVG_NAMESPACE.as_bytes())?;
+        settings.write_attribute_quote(writer)?;
+        crate::io::write_element_attributes(self, writer, settings)?;
+        crate::io::write_element_content(&self.children, writer, "svg", settings)
+    }
+}
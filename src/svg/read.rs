@@ -0,0 +1,368 @@
+use std::{borrow::Cow, io::BufRead};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader, Writer,
+};
+
+use crate::{
+    animation::{ElementAnimate, ElementAnimateTransform, ElementSet},
+    common::{CoreAttributes, NonStandardAttribute},
+    container::{
+        Element, ElementAnchor, ElementForeignObject, ElementGroup, ElementSwitch, ElementSymbol,
+        ElementUnknown, UnknownContent,
+    },
+    error::ReadError,
+    filter::{ElementFeGaussianBlur, ElementFeOffset, ElementFilter, FilterPrimitive},
+    io::AttributeBundle,
+    marker::ElementMarker,
+    paint::{ElementClipPath, ElementMask},
+    path::ElementPath,
+    script::ElementScript,
+    shape::{
+        ElementCircle, ElementEllipse, ElementLine, ElementPolygon, ElementPolyline, ElementRect,
+    },
+    style::ElementStyle,
+};
+
+use super::{ElementSvg, SVG_NAMESPACE};
+
+/// Element which collects attributes it doesn't recognize into its core
+/// attributes.
+trait ReadElement: AttributeBundle + Default {
+    fn core(&mut self) -> &mut CoreAttributes<'static>;
+}
+
+macro_rules! impl_read_element {
+    [$($element: ident),*] => {$(
+        impl ReadElement for $element<'static> {
+            #[inline]
+            fn core(&mut self) -> &mut CoreAttributes<'static> {
+                &mut self.core
+            }
+        }
+    )*};
+}
+
+impl_read_element![
+    ElementSvg,
+    ElementPath,
+    ElementGroup,
+    ElementRect,
+    ElementCircle,
+    ElementEllipse,
+    ElementLine,
+    ElementPolyline,
+    ElementPolygon,
+    ElementClipPath,
+    ElementMask,
+    ElementFilter,
+    ElementFeGaussianBlur,
+    ElementFeOffset,
+    ElementSymbol,
+    ElementMarker,
+    ElementAnchor,
+    ElementForeignObject,
+    ElementSwitch,
+    ElementScript,
+    ElementStyle,
+    ElementAnimate,
+    ElementSet,
+    ElementAnimateTransform
+];
+
+struct DocumentReader<R: BufRead> {
+    reader: Reader<R>,
+    buffer: Vec<u8>,
+}
+
+impl<R: BufRead> DocumentReader<R> {
+    fn next_event(&mut self) -> Result<Event<'static>, ReadError> {
+        self.buffer.clear();
+        Ok(self.reader.read_event_into(&mut self.buffer)?.into_owned())
+    }
+
+    fn decode<'b>(&self, bytes: &'b [u8]) -> Result<Cow<'b, str>, ReadError> {
+        Ok(self.reader.decoder().decode(bytes)?)
+    }
+
+    /// Reads attributes of `start` into a new element.
+    fn attributes<E: ReadElement>(&self, start: &BytesStart) -> Result<E, ReadError> {
+        let mut element = E::default();
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+            let name = self.decode(attribute.key.as_ref())?;
+            let value = attribute.decode_and_unescape_value(self.reader.decoder())?;
+
+            // written by elements that need it
+            if name == "xmlns" && value == SVG_NAMESPACE {
+                continue;
+            }
+            if element.read_attribute(&name, &value)? {
+                continue;
+            }
+            element.core().other.push(NonStandardAttribute {
+                name: Cow::Owned(name.into_owned()),
+                value: Cow::Owned(value.into_owned()),
+            });
+        }
+        Ok(element)
+    }
+
+    /// Reads children of an element until its end tag.
+    fn children(&mut self, empty: bool) -> Result<Vec<Element<'static>>, ReadError> {
+        let mut result = Vec::new();
+        if empty {
+            return Ok(result);
+        }
+        loop {
+            match self.next_event()? {
+                Event::Start(start) => result.push(self.element(&start, false)?),
+                Event::Empty(start) => result.push(self.element(&start, true)?),
+                Event::End(_) => return Ok(result),
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads character data of an element until its end tag.
+    fn text(&mut self, empty: bool) -> Result<String, ReadError> {
+        let mut result = String::new();
+        if empty {
+            return Ok(result);
+        }
+        let mut depth = 0usize;
+        loop {
+            match self.next_event()? {
+                Event::Text(text) => result.push_str(&text.unescape()?),
+                Event::CData(data) => result.push_str(&data.decode()?),
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth > 0 => depth -= 1,
+                Event::End(_) => return Ok(result),
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads content of an element until its end tag as markup.
+    fn markup(&mut self, empty: bool) -> Result<String, ReadError> {
+        let mut writer = Writer::new(Vec::new());
+        if empty {
+            return Ok(String::new());
+        }
+        let mut depth = 0usize;
+        loop {
+            let event = self.next_event()?;
+            match &event {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth > 0 => depth -= 1,
+                Event::End(_) => break,
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => {}
+            }
+            writer.write_event(event)?;
+        }
+        Ok(self.decode(&writer.into_inner())?.into_owned())
+    }
+
+    /// Skips content of an element until its end tag.
+    fn skip(&mut self, empty: bool) -> Result<(), ReadError> {
+        if empty {
+            return Ok(());
+        }
+        let mut depth = 0usize;
+        loop {
+            match self.next_event()? {
+                Event::Start(_) => depth += 1,
+                Event::End(_) if depth > 0 => depth -= 1,
+                Event::End(_) => return Ok(()),
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => {}
+            }
+        }
+    }
+
+    /// Reads an element without children, skipping any content it has.
+    fn leaf<E: ReadElement>(&mut self, start: &BytesStart, empty: bool) -> Result<E, ReadError> {
+        let element = self.attributes(start)?;
+        self.skip(empty)?;
+        Ok(element)
+    }
+
+    /// Reads filter primitives of a `<filter>` element until its end tag.
+    ///
+    /// Primitives which aren't supported are skipped, as filters can't hold
+    /// unknown elements.
+    fn filter_primitives(
+        &mut self,
+        empty: bool,
+    ) -> Result<Vec<FilterPrimitive<'static>>, ReadError> {
+        let mut result = Vec::new();
+        if empty {
+            return Ok(result);
+        }
+        loop {
+            let (start, empty) = match self.next_event()? {
+                Event::Start(start) => (start, false),
+                Event::Empty(start) => (start, true),
+                Event::End(_) => return Ok(result),
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => continue,
+            };
+            match self.decode(start.name().as_ref())?.as_ref() {
+                "feGaussianBlur" => {
+                    result.push(FilterPrimitive::GaussianBlur(self.leaf(&start, empty)?))
+                }
+                "feOffset" => result.push(FilterPrimitive::Offset(self.leaf(&start, empty)?)),
+                _ => self.skip(empty)?,
+            }
+        }
+    }
+
+    /// Reads an element that isn't supported, keeping all of its attributes
+    /// and content.
+    fn unknown(
+        &mut self,
+        tag: String,
+        start: &BytesStart,
+        empty: bool,
+    ) -> Result<ElementUnknown<'static>, ReadError> {
+        let mut attributes = Vec::new();
+        for attribute in start.attributes() {
+            let attribute = attribute?;
+            let name = self.decode(attribute.key.as_ref())?;
+            let value = attribute.decode_and_unescape_value(self.reader.decoder())?;
+            attributes.push(NonStandardAttribute {
+                name: Cow::Owned(name.into_owned()),
+                value: Cow::Owned(value.into_owned()),
+            });
+        }
+        Ok(ElementUnknown {
+            tag,
+            attributes,
+            children: self.mixed_content(empty)?,
+        })
+    }
+
+    /// Reads child elements and character data of an element until its end
+    /// tag.
+    fn mixed_content(&mut self, empty: bool) -> Result<Vec<UnknownContent<'static>>, ReadError> {
+        let mut result: Vec<UnknownContent> = Vec::new();
+        if empty {
+            return Ok(result);
+        }
+        loop {
+            let text = match self.next_event()? {
+                Event::Start(start) => {
+                    result.push(self.element(&start, false)?.into());
+                    continue;
+                }
+                Event::Empty(start) => {
+                    result.push(self.element(&start, true)?.into());
+                    continue;
+                }
+                Event::Text(text) => text.unescape()?.into_owned(),
+                Event::CData(data) => data.decode()?.into_owned(),
+                Event::End(_) => return Ok(result),
+                Event::Eof => return Err(ReadError::UnexpectedEof),
+                _ => continue,
+            };
+            // CDATA sections and text next to each other form a single node
+            match result.last_mut() {
+                Some(UnknownContent::Text(last)) => last.to_mut().push_str(&text),
+                _ => result.push(UnknownContent::Text(Cow::Owned(text))),
+            }
+        }
+    }
+
+    /// Reads an element with name of `start` tag.
+    fn element(&mut self, start: &BytesStart, empty: bool) -> Result<Element<'static>, ReadError> {
+        macro_rules! container {
+            ($variant: ident) => {{
+                let mut element: $variant = self.attributes(start)?;
+                element.children = self.children(empty)?;
+                element
+            }};
+        }
+
+        let name = self.decode(start.name().as_ref())?.into_owned();
+        let element = match name.as_str() {
+            "svg" => Element::Svg(container!(ElementSvg)),
+            "g" => Element::Group(container!(ElementGroup)),
+            "clipPath" => Element::ClipPath(container!(ElementClipPath)),
+            "mask" => Element::Mask(container!(ElementMask)),
+            "symbol" => Element::Symbol(container!(ElementSymbol)),
+            "marker" => Element::Marker(container!(ElementMarker)),
+            "a" => Element::Anchor(container!(ElementAnchor)),
+            "switch" => Element::Switch(container!(ElementSwitch)),
+            "filter" => {
+                let mut element: ElementFilter = self.attributes(start)?;
+                element.children = self.filter_primitives(empty)?;
+                Element::Filter(element)
+            }
+            "foreignObject" => {
+                let mut element: ElementForeignObject = self.attributes(start)?;
+                element.content = Cow::Owned(self.markup(empty)?);
+                Element::ForeignObject(element)
+            }
+            "script" => {
+                let mut element: ElementScript = self.attributes(start)?;
+                element.content = Cow::Owned(self.text(empty)?);
+                Element::Script(element)
+            }
+            "style" => {
+                let mut element: ElementStyle = self.attributes(start)?;
+                element.content = Cow::Owned(self.text(empty)?);
+                Element::Style(element)
+            }
+            "path" => Element::Path(self.leaf(start, empty)?),
+            "rect" => Element::Rect(self.leaf(start, empty)?),
+            "circle" => Element::Circle(self.leaf(start, empty)?),
+            "ellipse" => Element::Ellipse(self.leaf(start, empty)?),
+            "line" => Element::Line(self.leaf(start, empty)?),
+            "polyline" => Element::Polyline(self.leaf(start, empty)?),
+            "polygon" => Element::Polygon(self.leaf(start, empty)?),
+            "animate" => Element::Animate(self.leaf(start, empty)?),
+            "set" => Element::Set(self.leaf(start, empty)?),
+            "animateTransform" => Element::AnimateTransform(self.leaf(start, empty)?),
+            _ => Element::Unknown(self.unknown(name, start, empty)?),
+        };
+        Ok(element)
+    }
+}
+
+/// Reads an SVG document with an `<svg>` root element.
+///
+/// Attributes are read into typed fields of elements, and attributes that
+/// aren't supported by an element are stored in
+/// [`CoreAttributes::other`](crate::common::CoreAttributes::other). Elements
+/// which aren't supported are read as [`ElementUnknown`]s, except for filter
+/// primitives which are skipped. Content of elements that can't have
+/// children, such as `<path>`, is skipped.
+///
+/// The XML declaration, document type declaration, comments and processing
+/// instructions aren't preserved.
+pub fn read_document(reader: impl BufRead) -> Result<ElementSvg<'static>, ReadError> {
+    let mut reader = DocumentReader {
+        reader: Reader::from_reader(reader),
+        buffer: Vec::new(),
+    };
+    loop {
+        let (start, empty) = match reader.next_event()? {
+            Event::Start(start) => (start, false),
+            Event::Empty(start) => (start, true),
+            Event::Eof => return Err(ReadError::MissingRoot),
+            _ => continue,
+        };
+
+        let name = reader.decode(start.name().as_ref())?.into_owned();
+        if name != "svg" {
+            return Err(ReadError::UnexpectedRoot(name));
+        }
+        let mut root: ElementSvg = reader.attributes(&start)?;
+        root.children = reader.children(empty)?;
+        return Ok(root);
+    }
+}
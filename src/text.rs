@@ -0,0 +1,37 @@
+keyword_enum! {
+    /// Alignment of a string of text relative to its starting point.
+    ///
+    /// [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/text.html#TextAnchorProperty)
+    pub enum TextAnchor {
+        #[default]
+        Start => "start",
+        Middle => "middle",
+        End => "end",
+    }
+}
+
+keyword_enum! {
+    /// Baseline used to align text to its parent.
+    ///
+    /// `text-bottom` and `text-top` values are part of SVG 2 specification.
+    ///
+    /// - [SVG 1.1 documentation](https://www.w3.org/TR/SVG11/text.html#DominantBaselineProperty)
+    /// - [SVG 2 documentation](https://www.w3.org/TR/css-inline-3/#dominant-baseline-property)
+    pub enum DominantBaseline {
+        #[default]
+        Auto => "auto",
+        UseScript => "use-script",
+        NoChange => "no-change",
+        ResetSize => "reset-size",
+        Ideographic => "ideographic",
+        Alphabetic => "alphabetic",
+        Hanging => "hanging",
+        Mathematical => "mathematical",
+        Central => "central",
+        Middle => "middle",
+        TextAfterEdge => "text-after-edge",
+        TextBeforeEdge => "text-before-edge",
+        TextBottom => "text-bottom",
+        TextTop => "text-top",
+    }
+}
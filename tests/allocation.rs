@@ -0,0 +1,45 @@
+//! Checks that string-backed values are pushed into lists without
+//! intermediate allocations.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use structuredvg::common::{DelimitedValues, LanguageTag};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn push_language_tag_without_allocating() {
+    let mut languages: DelimitedValues<',', LanguageTag> = DelimitedValues::with_capacity(64);
+    let tags = [
+        LanguageTag::new("en-US").unwrap(),
+        LanguageTag::new("de").unwrap(),
+    ];
+
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    for tag in tags {
+        languages.push(tag);
+    }
+    let after = ALLOCATIONS.load(Ordering::SeqCst);
+
+    assert_eq!(after - before, 0);
+    assert_eq!(languages.as_ref(), "en-US,de");
+}
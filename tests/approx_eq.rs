@@ -0,0 +1,35 @@
+#![cfg(feature = "path")]
+
+use structuredvg::path::PathData;
+
+#[test]
+fn differently_encoded_paths() {
+    let absolute: PathData = "M10 10 L20 10 L20 20 C20 25 25 30 30 30 C35 30 40 35 40 40 Z"
+        .parse()
+        .unwrap();
+    let relative: PathData = "m10 10 h10 v10 c0 5 5 10 10 10 s10 5 10 10 z"
+        .parse()
+        .unwrap();
+
+    assert!(absolute.approx_eq(&relative, 1e-6));
+    assert!(relative.approx_eq(&absolute, 1e-6));
+}
+
+#[test]
+fn different_paths() {
+    let a: PathData = "M0 0 L10 0".parse().unwrap();
+
+    assert!(!a.approx_eq(&"M0 0 L10 0.1".parse().unwrap(), 0.01));
+    assert!(a.approx_eq(&"M0 0 L10 0.1".parse().unwrap(), 0.1));
+    assert!(!a.approx_eq(&"M0 0 L10 0 L20 0".parse().unwrap(), 0.01));
+    assert!(!a.approx_eq(&"M0 0 Q5 0 10 0".parse().unwrap(), 0.01));
+}
+
+#[test]
+fn arcs_are_flattened() {
+    let a: PathData = "M0 0 A10 10 0 0 1 20 0".parse().unwrap();
+    let b: PathData = "M0 0 a10 10 0 0 1 20 0".parse().unwrap();
+
+    assert!(a.approx_eq(&b, 1e-6));
+    assert!(!a.approx_eq(&"M0 0 A10 10 0 0 0 20 0".parse().unwrap(), 1e-6));
+}
@@ -0,0 +1,125 @@
+use structuredvg::{
+    common::PositionSize,
+    container::{Element, ElementGroup},
+    math::{Bounded, Coordinate, NonNegativeLength, Point, Rect},
+    shape::{
+        ElementCircle, ElementEllipse, ElementLine, ElementPolygon, ElementPolyline, ElementRect,
+        PointList,
+    },
+};
+
+fn rect(x0: f32, y0: f32, x1: f32, y1: f32) -> Option<Rect> {
+    Some(Rect::from_points(Point::new(x0, y0), Point::new(x1, y1)))
+}
+
+fn coordinate(value: &str) -> Option<Coordinate> {
+    Some(value.parse().unwrap())
+}
+
+fn length(value: &str) -> Option<NonNegativeLength> {
+    Some(value.parse().unwrap())
+}
+
+#[test]
+fn rect_bounds() {
+    let mut element = ElementRect {
+        position_size: Box::new(PositionSize {
+            x: coordinate("10"),
+            y: coordinate("20"),
+            width: length("30"),
+            height: length("40"),
+        }),
+        ..Default::default()
+    };
+    assert_eq!(element.bounding_box(), rect(10., 20., 40., 60.));
+
+    element.position_size.height = None;
+    assert_eq!(element.bounding_box(), None);
+}
+
+#[test]
+fn circle_and_ellipse_bounds() {
+    let circle = ElementCircle {
+        cx: coordinate("5"),
+        cy: coordinate("5"),
+        r: length("2"),
+        ..Default::default()
+    };
+    assert_eq!(circle.bounding_box(), rect(3., 3., 7., 7.));
+    assert_eq!(ElementCircle::default().bounding_box(), None);
+
+    let ellipse = ElementEllipse {
+        rx: length("4"),
+        ry: length("1"),
+        ..Default::default()
+    };
+    assert_eq!(ellipse.bounding_box(), rect(-4., -1., 4., 1.));
+}
+
+#[test]
+fn line_bounds() {
+    let line = ElementLine {
+        x1: coordinate("10"),
+        y1: coordinate("0"),
+        x2: coordinate("-5"),
+        y2: coordinate("8"),
+        ..Default::default()
+    };
+    assert_eq!(line.bounding_box(), rect(-5., 0., 10., 8.));
+
+    let percentage = ElementLine {
+        x2: coordinate("50%"),
+        ..Default::default()
+    };
+    assert_eq!(percentage.bounding_box(), None);
+}
+
+#[test]
+fn point_list_bounds() {
+    let points = PointList::from_slice(&[(0.0, 5.0), (10.0, -2.0), (3.0, 7.0)]);
+    let polyline = ElementPolyline {
+        points: Some(points.clone()),
+        ..Default::default()
+    };
+    let polygon = ElementPolygon {
+        points: Some(points),
+        ..Default::default()
+    };
+    assert_eq!(polyline.bounding_box(), rect(0., -2., 10., 7.));
+    assert_eq!(polygon.bounding_box(), rect(0., -2., 10., 7.));
+    assert_eq!(ElementPolygon::default().bounding_box(), None);
+}
+
+#[cfg(feature = "path")]
+#[test]
+fn path_bounds() {
+    use structuredvg::path::ElementPath;
+
+    let path = ElementPath {
+        d: Some("M0 0 L10 0 L10 -5 Z".parse().unwrap()),
+        ..Default::default()
+    };
+    assert_eq!(path.bounding_box(), rect(0., -5., 10., 0.));
+    assert_eq!(ElementPath::default().bounding_box(), None);
+}
+
+#[test]
+fn group_bounds() {
+    let group = ElementGroup {
+        children: vec![
+            Element::Circle(ElementCircle {
+                r: length("1"),
+                ..Default::default()
+            }),
+            Element::Line(ElementLine {
+                x2: coordinate("5"),
+                y2: coordinate("5"),
+                ..Default::default()
+            }),
+            Element::Group(ElementGroup::default()),
+        ],
+        ..Default::default()
+    };
+    assert_eq!(group.bounding_box(), rect(-1., -1., 5., 5.));
+    assert_eq!(ElementGroup::default().bounding_box(), None);
+}
@@ -0,0 +1,34 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::{CachedPath, PathSegment},
+};
+
+#[test]
+fn cached_d_string() {
+    let mut path = CachedPath::new("M0 0L10 10".parse().unwrap());
+    let settings = WriteSettings::default();
+
+    let first = path.to_d_string(&settings);
+    assert_eq!(first, "M0.0000 0.0000L10.0000 10.0000");
+    assert!(matches!(path.to_d_string(&settings), Cow::Borrowed(_)));
+
+    let rounded = WriteSettings {
+        precision: 0,
+        ..Default::default()
+    };
+    assert_eq!(
+        path.to_d_string(&rounded),
+        Cow::<str>::Owned("M0 0L10 10".into())
+    );
+    assert_eq!(
+        path.write_to_string(&settings),
+        "M0.0000 0.0000L10.0000 10.0000"
+    );
+
+    path.data_mut().push(PathSegment::close());
+    assert_eq!(path.to_d_string(&rounded), "M0 0L10 10z");
+}
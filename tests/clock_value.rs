@@ -0,0 +1,48 @@
+use structuredvg::{animation::ClockValue, io::FromStringUnsafe};
+
+#[test]
+fn parse_clock_values() {
+    let seconds = |value: &str| value.parse::<ClockValue>().unwrap().as_seconds();
+
+    assert_eq!(seconds("00:01:30"), Some(90.0));
+    assert_eq!(seconds("01:30"), Some(90.0));
+    assert_eq!(seconds("1.5min"), Some(90.0));
+    assert_eq!(seconds("90s"), Some(90.0));
+    assert_eq!(seconds("90000ms"), Some(90.0));
+    assert_eq!(seconds("indefinite"), None);
+    assert!("1:30".parse::<ClockValue>().is_err());
+}
+
+#[test]
+fn fractional_minutes() {
+    // only seconds can have a fraction, minutes are exactly two digits
+    assert_eq!("00:30.5".parse(), Ok(ClockValue::Seconds(30.5)));
+    assert!("00.5:30".parse::<ClockValue>().is_err());
+    assert!("00:00.5:30".parse::<ClockValue>().is_err());
+    assert!("00:30.".parse::<ClockValue>().is_err());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn write_with_settings() {
+    use structuredvg::io::{NumberFormat, Writable, WriteSettings};
+
+    let value = ClockValue::Seconds(0.25);
+    let short = WriteSettings::builder()
+        .precision(2)
+        .number_format(NumberFormat {
+            leading_zero: false,
+        })
+        .build();
+
+    assert_eq!(value.write_to_string(&WriteSettings::default()), "0.2500s");
+    assert_eq!(value.write_to_string(&short), ".25s");
+    assert_eq!(ClockValue::Indefinite.write_to_string(&short), "indefinite");
+}
+
+#[test]
+fn unchecked_invalid_clock_value() {
+    // invalid values don't turn into an indefinite duration
+    let value = unsafe { <ClockValue as FromStringUnsafe>::from("soon".to_string()) };
+    assert_eq!(value, ClockValue::Seconds(0.0));
+}
@@ -0,0 +1,84 @@
+use structuredvg::style::{Color, Paint};
+
+fn parse(value: &str) -> Color {
+    value.parse().unwrap()
+}
+
+#[test]
+fn parse_alpha() {
+    assert_eq!(parse("#80808080"), Color::rgba(128, 128, 128, 128));
+    assert_eq!(parse("#f80c"), Color::rgba(255, 136, 0, 204));
+    assert_eq!(parse("rgba(0,0,0,0.5)"), Color::rgba(0, 0, 0, 128));
+    assert_eq!(parse("rgb(0 0 0 / 50%)"), Color::rgba(0, 0, 0, 128));
+    assert_eq!(parse("transparent").alpha(), 0);
+}
+
+#[test]
+fn parse_hsl() {
+    assert_eq!(parse("hsl(120,50%,50%)"), Color::rgb(64, 191, 64));
+    assert_eq!(
+        parse("hsla(240deg, 100%, 50%, 0.25)"),
+        Color::rgba(0, 0, 255, 64)
+    );
+    assert_eq!(parse("hsl(-240, 100%, 50%)"), Color::rgb(0, 255, 0));
+}
+
+#[test]
+fn clamp_channels() {
+    assert_eq!(parse("rgb(300, -20, 50%)"), Color::rgb(255, 0, 128));
+    assert_eq!(parse("rgba(0, 0, 0, 2)"), Color::rgb(0, 0, 0));
+    assert_eq!(parse("hsl(0, 150%, 50%)"), Color::rgb(255, 0, 0));
+}
+
+#[test]
+fn reject_invalid() {
+    for value in [
+        "",
+        "#12",
+        "#ggg",
+        "reddish",
+        "rgb(1, 2)",
+        "hsl(0, 50, 50)",
+        "rgb(1, 2, 3",
+    ] {
+        assert!(value.parse::<Color>().is_err(), "{value}");
+    }
+}
+
+#[test]
+fn parse_named() {
+    assert_eq!(parse("red"), Color::rgb(255, 0, 0));
+    assert_eq!(parse("RebeccaPurple"), Color::rgb(102, 51, 153));
+    assert_eq!(parse(" aliceblue "), Color::rgb(240, 248, 255));
+    assert_eq!(parse("yellowgreen"), Color::rgb(154, 205, 50));
+    assert_eq!(parse("grey"), parse("gray"));
+}
+
+#[test]
+fn compact_display() {
+    assert_eq!(Color::rgb(255, 136, 0).to_string(), "#f80");
+    assert_eq!(Color::rgb(18, 52, 86).to_string(), "#123456");
+    assert_eq!(parse("red").to_string(), "#f00");
+}
+
+#[test]
+fn display_alpha() {
+    // `rgba()` is written instead of shorter `#rgba` and `#rrggbbaa`, which
+    // SVG 1.1 renderers don't support
+    assert_eq!(Color::rgba(0, 0, 0, 0).to_string(), "rgba(0,0,0,0)");
+    assert_eq!(parse("#f80c").to_string(), "rgba(255,136,0,0.8)");
+    assert_eq!(parse("rgba(0,0,0,0.5)").to_string(), "rgba(0,0,0,0.5)");
+    assert_eq!(parse("#80808080").to_string(), "rgba(128,128,128,0.5)");
+    for a in 0..255 {
+        let color = Color::rgba(18, 52, 86, a);
+        assert_eq!(parse(&color.to_string()), color);
+    }
+}
+
+#[test]
+fn paint_color() {
+    let paint = Paint::from(Color::rgb(64, 191, 64));
+    assert_eq!(paint.to_string(), "#40bf40");
+    assert_eq!(paint.parse_color(), Some(Ok(Color::rgb(64, 191, 64))));
+    assert_eq!(Paint::None.parse_color(), None);
+}
@@ -0,0 +1,83 @@
+use std::borrow::Cow;
+
+use structuredvg::{
+    common::{ConditionalProcessing, CoreAttributes, DelimitedValues, LanguageTag, ProcessingEnv},
+    container::{Element, ElementGroup, ElementSwitch},
+};
+
+fn env() -> ProcessingEnv<'static> {
+    ProcessingEnv {
+        features: [Cow::Borrowed("http://www.w3.org/TR/SVG11/feature#Shape")].into(),
+        extensions: Default::default(),
+        languages: vec![LanguageTag::new("en").unwrap()],
+    }
+}
+
+fn features(values: &[&str]) -> Option<DelimitedValues<' '>> {
+    Some(DelimitedValues::with_values(
+        values.iter().map(|it| it.to_string()),
+    ))
+}
+
+#[test]
+fn unsupported_feature() {
+    let mut conditions = ConditionalProcessing::default();
+    assert!(conditions.is_satisfied(&env()));
+
+    conditions.required_features = features(&["http://www.w3.org/TR/SVG11/feature#Shape"]);
+    assert!(conditions.is_satisfied(&env()));
+
+    conditions.required_features = features(&[
+        "http://www.w3.org/TR/SVG11/feature#Shape",
+        "http://www.w3.org/TR/SVG11/feature#Font",
+    ]);
+    assert!(!conditions.is_satisfied(&env()));
+
+    conditions.required_features = features(&[]);
+    assert!(!conditions.is_satisfied(&env()));
+
+    conditions.required_features = None;
+    conditions.required_extensions = features(&["http://example.org/ext"]);
+    assert!(!conditions.is_satisfied(&env()));
+}
+
+fn group(id: &'static str, language: &'static str) -> Element<'static> {
+    let mut languages = DelimitedValues::new();
+    languages.push(LanguageTag::new(language).unwrap());
+    Element::Group(ElementGroup {
+        conditional_processing: Box::new(ConditionalProcessing {
+            system_language: Some(languages),
+            ..Default::default()
+        }),
+        core: Box::new(CoreAttributes {
+            id: Some(Cow::Borrowed(id)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    })
+}
+
+fn selected_id<'a>(switch: &'a ElementSwitch, env: &ProcessingEnv) -> Option<&'a str> {
+    match switch.select(env)? {
+        Element::Group(group) => group.core.id.as_deref(),
+        _ => None,
+    }
+}
+
+#[test]
+fn switch_selects_language() {
+    let switch = ElementSwitch {
+        children: vec![group("german", "de"), group("english", "en-US")],
+        ..Default::default()
+    };
+
+    assert_eq!(selected_id(&switch, &env()), Some("english"));
+
+    let mut german = env();
+    german.languages = vec![LanguageTag::new("de").unwrap()];
+    assert_eq!(selected_id(&switch, &german), Some("german"));
+
+    let mut french = env();
+    french.languages = vec![LanguageTag::new("fr").unwrap()];
+    assert!(switch.select(&french).is_none());
+}
@@ -0,0 +1,48 @@
+use structuredvg::math::{Coordinate, Length, NonNegativeLength, Percentage, PositiveNumber, Unit};
+
+#[test]
+fn parse_coordinate() {
+    assert_eq!(
+        "50%".parse::<Coordinate>(),
+        Ok(Coordinate::Percentage(Percentage::new(50.0)))
+    );
+    assert_eq!(
+        "10px".parse::<Coordinate>(),
+        Ok(Coordinate::Length(Length::new(10.0, Unit::Px)))
+    );
+    assert!("10%px".parse::<Coordinate>().is_err());
+}
+
+#[test]
+fn resolve_coordinate() {
+    let half: Coordinate = "50%".parse().unwrap();
+    assert_eq!(half.resolve(300.0, 96.0, None), Some(150.0));
+    assert_eq!(half.to_user_units(), None);
+
+    let inch = Coordinate::Length(Length::new(1.0, Unit::In));
+    assert_eq!(inch.resolve(300.0, 72.0, None), Some(72.0));
+
+    let em = Coordinate::Length(Length::new(2.0, Unit::Em));
+    assert_eq!(em.resolve(300.0, 96.0, None), None);
+    assert_eq!(em.resolve(300.0, 96.0, Some(16.0)), Some(32.0));
+}
+
+#[test]
+fn non_negative_length() {
+    assert_eq!(NonNegativeLength::new((-1.0).into()), None);
+    assert!(NonNegativeLength::new(Length::new(5.0, Unit::Px)).is_some());
+    assert!("-5px".parse::<NonNegativeLength>().is_err());
+    assert_eq!(
+        "5px".parse::<NonNegativeLength>().map(|it| it.into_inner()),
+        Ok(Length::new(5.0, Unit::Px))
+    );
+}
+
+#[test]
+fn format_positive_number() {
+    let number = PositiveNumber::new(1.25).unwrap();
+    assert_eq!(format!("{:.1}", number), "1.2");
+    assert_eq!(format!("{:.1}", PositiveNumber::new(0.75).unwrap()), "0.8");
+    assert_eq!(format!("{:.2}", number), "1.25");
+    assert_eq!(number.to_string(), "1.2500");
+}
@@ -0,0 +1,17 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::PathData,
+};
+
+#[test]
+fn count_written_bytes() {
+    let path: PathData = "M0 0 L10 10 C1 2 3 4 5 6 z".parse().unwrap();
+    let settings = WriteSettings::default();
+
+    let mut buffer = Vec::new();
+    let count = path.write_to_counted(&mut buffer, &settings).unwrap();
+    assert_eq!(count, buffer.len());
+    assert_eq!(count, path.write_to_string(&settings).len());
+}
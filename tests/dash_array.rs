@@ -0,0 +1,23 @@
+use structuredvg::{error::DashError, style::DashArray};
+
+#[test]
+fn reject_invalid_dashes() {
+    assert_eq!(DashArray::new(vec![5.0, -5.0]), Err(DashError::Negative(1)));
+    assert_eq!(DashArray::new(vec![f32::NAN]), Err(DashError::NotFinite(0)));
+    assert_eq!("5 -5".parse::<DashArray>(), Err(DashError::Negative(1)));
+    assert_eq!(
+        "5, five".parse::<DashArray>(),
+        Err(DashError::InvalidNumber(1))
+    );
+}
+
+#[test]
+fn solid_dashes() {
+    let zeros = DashArray::new(vec![0.0, 0.0]).unwrap();
+    assert!(zeros.is_solid());
+    assert!(DashArray::default().is_solid());
+    assert!(!DashArray::new(vec![5.0, 0.0]).unwrap().is_solid());
+
+    assert_eq!("none".parse(), Ok(DashArray::default()));
+    assert_eq!("0,0".parse(), Ok(zeros));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "html")]
+
+use structuredvg::common::CoreAttributes;
+
+#[test]
+fn set_then_get() {
+    let mut core = CoreAttributes::default();
+    core.set_data("note", "first");
+    core.set_data("other", "second");
+
+    assert_eq!(core.get_data("note"), Some("first"));
+    assert_eq!(core.get_data("other"), Some("second"));
+    assert_eq!(core.get_data("data-note"), None);
+    assert_eq!(core.data[0].name, "data-note");
+}
+
+#[test]
+fn overwrite_and_remove() {
+    let mut core = CoreAttributes::default();
+    core.set_data("note", "first");
+    core.set_data("note", "second".to_string());
+
+    assert_eq!(core.data.len(), 1);
+    assert_eq!(core.get_data("note"), Some("second"));
+
+    assert!(core.remove_data("note"));
+    assert!(!core.remove_data("note"));
+    assert_eq!(core.get_data("note"), None);
+}
@@ -0,0 +1,149 @@
+use std::borrow::Cow;
+
+use structuredvg::style::{Declaration, DeclarationList};
+
+fn property<'a>(name: &'a str, value: &'a str) -> Declaration<'a> {
+    Declaration::Property {
+        name: Cow::Borrowed(name),
+        value: Cow::Borrowed(value),
+    }
+}
+
+#[test]
+fn iterate_in_order() {
+    let mut list = DeclarationList::default();
+    list.push_property("fill", "red");
+    list.push_property("stroke", "blue");
+
+    let names: Vec<_> = list.iter().filter_map(|it| it.name()).collect();
+    assert_eq!(names, ["fill", "stroke"]);
+    assert_eq!(list[0], property("fill", "red"));
+    assert_eq!(list[1], property("stroke", "blue"));
+
+    list[1] = property("stroke", "green");
+    for declaration in &mut list {
+        if let Declaration::Property { value, .. } = declaration {
+            *value = Cow::Owned(value.to_uppercase());
+        }
+    }
+    assert_eq!(
+        list.into_iter().collect::<Vec<_>>(),
+        [property("fill", "RED"), property("stroke", "GREEN")]
+    );
+}
+
+#[test]
+fn preserve_comments() {
+    let list: DeclarationList = "/* note */ fill:red".parse().unwrap();
+    assert_eq!(
+        list.iter().cloned().collect::<Vec<_>>(),
+        [
+            Declaration::Comment(Cow::Borrowed("note")),
+            property("fill", "red")
+        ]
+    );
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn write_comments() {
+    use structuredvg::io::{Writable, WriteSettings};
+
+    let settings = WriteSettings::default();
+    let list: DeclarationList = "/* note */ fill:red; /* end */".parse().unwrap();
+    let written = list.write_to_string(&settings);
+    assert_eq!(written, "/* note */fill:red;/* end */");
+    assert_eq!(written.parse(), Ok(list));
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn custom_property_references() {
+    use structuredvg::{
+        io::{Writable, WriteSettings},
+        style::Var,
+    };
+
+    let settings = WriteSettings::default();
+    let reference = Var::new("--accent").with_fallback("blue");
+    let written = reference.write_to_string(&settings);
+    assert_eq!(written, "var(--accent, blue)");
+    assert_eq!(written.parse(), Ok(reference.clone()));
+    assert!("var(accent)".parse::<Var>().is_err());
+
+    let mut list = DeclarationList::default();
+    list.push_custom("--accent", "red");
+    list.push_property("fill", reference.to_string());
+    let written = list.write_to_string(&settings);
+    assert_eq!(written, "--accent:red;fill:var(--accent, blue)");
+
+    let parsed: DeclarationList = written.parse().unwrap();
+    assert_eq!(parsed, list);
+    assert!(matches!(parsed[0], Declaration::Custom { .. }));
+    match &parsed[1] {
+        Declaration::Property { value, .. } => assert_eq!(value.parse(), Ok(reference)),
+        other => panic!("expected a property, got {:?}", other),
+    }
+}
+
+#[test]
+fn merge_declarations() {
+    let mut base: DeclarationList = "fill:red; stroke:blue !important; /* base */"
+        .parse()
+        .unwrap();
+    let theme: DeclarationList = "/* theme */ fill:green; stroke:black; opacity:0.5"
+        .parse()
+        .unwrap();
+    base.merge(&theme);
+    assert_eq!(
+        base,
+        "fill:green; stroke:blue !important; /* base */ opacity:0.5"
+            .parse()
+            .unwrap()
+    );
+
+    let important: DeclarationList = "stroke:white !important".parse().unwrap();
+    base.merge(&important);
+    assert_eq!(base[1], property("stroke", "white !important"));
+    assert_eq!(base.iter().count(), 4);
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn write_escaped() {
+    use structuredvg::io::{Writable, WriteSettings};
+
+    let mut list = DeclarationList::default();
+    list.push_property("font-family", r#""Times" & <b>"#);
+    list.push_custom("--note", "a < b");
+
+    let settings = WriteSettings::default();
+    assert_eq!(
+        list.write_to_string(&settings),
+        "font-family:&quot;Times&quot; &amp; &lt;b>;--note:a &lt; b"
+    );
+
+    let single = WriteSettings::builder().attribute_quote('\'').build();
+    assert_eq!(
+        list.write_to_string(&single),
+        r#"font-family:"Times" &amp; &lt;b>;--note:a &lt; b"#
+    );
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn write_comment_terminator() {
+    use structuredvg::io::{Writable, WriteSettings};
+
+    let mut list = DeclarationList::default();
+    list.declarations
+        .push(Declaration::Comment(Cow::Borrowed("a */ fill:red; /* b")));
+    list.push_property("stroke", "blue");
+
+    let written = list.write_to_string(&WriteSettings::default());
+    assert_eq!(written, "/* a * *//* / fill:red; /* b */stroke:blue");
+
+    let parsed: DeclarationList = written.parse().unwrap();
+    let names: Vec<_> = parsed.iter().filter_map(|it| it.name()).collect();
+    assert_eq!(names, ["stroke"]);
+}
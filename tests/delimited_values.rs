@@ -0,0 +1,129 @@
+use structuredvg::{common::DelimitedValues, math::PositiveNumber};
+
+fn list(values: &[&str]) -> DelimitedValues<' '> {
+    DelimitedValues::with_values(values.iter().map(|it| it.to_string()))
+}
+
+#[test]
+fn compare_as_set() {
+    let a = list(&["a", "b"]);
+    let b = list(&["b", "a"]);
+
+    assert_ne!(a, b);
+    assert!(a.eq_as_set(&b));
+    assert!(a.eq_as_set(&list(&["b", "a", "b"])));
+    assert!(!a.eq_as_set(&list(&["a"])));
+    assert!(list(&[]).eq_as_set(&list(&[])));
+}
+
+#[test]
+fn sorted_copy() {
+    let values = list(&["c", "a", "b"]);
+
+    assert_eq!(values.to_sorted(), list(&["a", "b", "c"]));
+    assert_eq!(values, list(&["c", "a", "b"]));
+    assert_eq!(values.to_sorted(), list(&["b", "c", "a"]).to_sorted());
+}
+
+#[test]
+fn drain_into_other_list() {
+    let mut source = list(&["a", "b"]);
+    let mut target = list(&["c"]);
+
+    source.drain_into(&mut target);
+    assert_eq!(target.iter().collect::<Vec<_>>(), ["c", "a", "b"]);
+    assert_eq!(source.iter().count(), 0);
+
+    source.drain_into(&mut target);
+    assert_eq!(target, list(&["c", "a", "b"]));
+
+    target.drain_into(&mut source);
+    assert_eq!(source, list(&["c", "a", "b"]));
+    assert_eq!(target, list(&[]));
+}
+
+#[test]
+fn append_copies() {
+    let mut a = list(&["a"]);
+    a.append(&list(&["b", "c"]));
+    a.append(&list(&[]));
+    assert_eq!(a, list(&["a", "b", "c"]));
+}
+
+#[test]
+fn compare_typed_values() {
+    let one = PositiveNumber::new(1.0).unwrap();
+    let half = PositiveNumber::new(0.5).unwrap();
+    let mut numbers: DelimitedValues<',', PositiveNumber> = DelimitedValues::new();
+    unsafe {
+        numbers.push_str("2");
+        numbers.push_str("1.0");
+        numbers.push_str(" 0.50");
+    }
+
+    assert!(numbers.contains_value(&one));
+    assert!(numbers.contains_value(&half));
+    assert!(!numbers.contains_value(&PositiveNumber::new(3.0).unwrap()));
+
+    assert!(numbers.remove_value(&one));
+    assert!(!numbers.remove_value(&one));
+    assert_eq!(numbers.as_ref(), "2, 0.50");
+    assert!(numbers.remove_value(&half));
+    assert_eq!(numbers.as_ref(), "2");
+}
+
+#[test]
+fn iterate_empty() {
+    assert_eq!(DelimitedValues::<' '>::new().iter().count(), 0);
+    assert_eq!(DelimitedValues::<' '>::new().iter_values().count(), 0);
+    assert_eq!(list(&[]).iter().count(), 0);
+}
+
+#[test]
+fn push_delimits_values() {
+    let mut classes: DelimitedValues<' '> = DelimitedValues::new();
+    classes.push("a".to_string());
+    assert_eq!(classes.as_ref(), "a");
+    classes.push("b".to_string());
+    unsafe {
+        classes.push_str("c");
+    }
+    assert_eq!(classes.as_ref(), "a b c");
+    assert_eq!(classes.to_string(), "a b c");
+
+    let mut numbers: DelimitedValues<',', PositiveNumber> = DelimitedValues::new();
+    numbers.push(PositiveNumber::new(1.0).unwrap());
+    numbers.push(PositiveNumber::new(2.5).unwrap());
+    assert_eq!(numbers.as_ref(), "1.0000,2.5000");
+}
+
+#[test]
+fn dedup_and_retain() {
+    let mut classes = list(&["a", "b", "a", "c", "b"]);
+    classes.dedup();
+    assert_eq!(classes.as_ref(), "a b c");
+
+    classes.retain(|it| it != "b");
+    assert_eq!(classes.as_ref(), "a c");
+    classes.retain(|_| false);
+    assert_eq!(classes, list(&[]));
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn write_with_separator() {
+    use structuredvg::{
+        common::SeparatedValues,
+        io::{Writable, WriteSettings},
+    };
+
+    let settings = WriteSettings::default();
+    let mut classes: SeparatedValues<','> = SeparatedValues::parse(", ", "a,b ,, c").unwrap();
+    assert_eq!(classes.as_ref(), "a,b,c");
+    assert_eq!(classes.write_to_string(&settings), "a, b, c");
+
+    classes.set_separator(",");
+    assert_eq!(classes.write_to_string(&settings), "a,b,c");
+    classes.clear();
+    assert_eq!(classes.write_to_string(&settings), "");
+}
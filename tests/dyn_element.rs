@@ -0,0 +1,94 @@
+#![cfg(feature = "write")]
+
+use structuredvg::{
+    container::{DynElement, Element, ElementGroup, Fragment},
+    io::{write_children, Writable, WriteSettings},
+};
+
+#[derive(Debug)]
+struct Badge {
+    label: &'static str,
+}
+
+impl Writable for Badge {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        _settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write!(writer, "<badge label=\"{}\"/>", self.label)
+    }
+}
+
+impl DynElement for Badge {
+    fn tag_name(&self) -> &str {
+        "badge"
+    }
+
+    fn write_dyn(
+        &self,
+        mut writer: &mut dyn std::io::Write,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        self.write_to(&mut writer, settings)
+    }
+}
+
+#[test]
+fn custom_element_in_group() {
+    let group = ElementGroup {
+        children: vec![Element::custom(Badge { label: "new" })],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        group.write_to_string(&WriteSettings::default()),
+        r#"<g><badge label="new"/></g>"#
+    );
+
+    // cloning shares the custom element
+    let copy = group.clone();
+    assert_eq!(
+        copy.write_to_string(&WriteSettings::default()),
+        group.write_to_string(&WriteSettings::default())
+    );
+}
+
+#[test]
+fn boxed_children() {
+    let children: Vec<Box<dyn DynElement>> = vec![
+        Box::new(Badge { label: "a" }),
+        Box::new(Badge { label: "b" }),
+    ];
+    assert_eq!(children[0].tag_name(), "badge");
+
+    let mut buffer = Vec::new();
+    write_children(&children, &mut buffer, &WriteSettings::default()).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        r#"<badge label="a"/><badge label="b"/>"#
+    );
+}
+
+#[test]
+fn fragment_without_wrapper() {
+    let fragment = Fragment {
+        children: vec![
+            Element::custom(Badge { label: "a" }),
+            Element::Group(ElementGroup::default()),
+        ],
+    };
+    assert_eq!(
+        fragment.write_to_string(&WriteSettings::default()),
+        r#"<badge label="a"/><g/>"#
+    );
+
+    let group = ElementGroup {
+        children: vec![Element::Fragment(fragment)],
+        ..Default::default()
+    };
+    assert_eq!(
+        group.write_to_string(&WriteSettings::builder().indent(2).build()),
+        "<g>\n  <badge label=\"a\"/>\n  <g/>\n</g>"
+    );
+}
@@ -0,0 +1,22 @@
+use structuredvg::{
+    error::{Error, ParseError},
+    math::Length,
+    style::LineCap,
+};
+
+fn parse_both(length: &str, cap: &str) -> Result<(Length, LineCap), Error> {
+    Ok((length.parse()?, cap.parse()?))
+}
+
+#[test]
+fn specific_errors_convert() {
+    assert!(parse_both("12px", "round").is_ok());
+    assert!(matches!(
+        parse_both("12 apples", "round"),
+        Err(Error::Parse(ParseError::Length(_)))
+    ));
+    assert!(matches!(
+        parse_both("12px", "pointy"),
+        Err(Error::Parse(ParseError::Keyword(_)))
+    ));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "write")]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    container::Element,
+    filter::{ElementFeGaussianBlur, ElementFeOffset, ElementFilter, FilterPrimitive},
+    io::{Writable, WriteSettings},
+    math::PositiveNumber,
+    paint::Units,
+};
+
+#[test]
+fn blur_filter() {
+    let mut blur = ElementFeGaussianBlur {
+        input: Some(Cow::Borrowed("SourceAlpha")),
+        std_deviation: PositiveNumber::new(2.5),
+        ..Default::default()
+    };
+    blur.primitive.result = Some(Cow::Borrowed("blur"));
+    let offset = ElementFeOffset {
+        dx: Some(1.0),
+        dy: Some(-1.0),
+        ..Default::default()
+    };
+
+    let mut filter = ElementFilter {
+        filter_units: Some(Units::UserSpaceOnUse),
+        children: vec![
+            FilterPrimitive::GaussianBlur(blur),
+            FilterPrimitive::Offset(offset),
+        ],
+        ..Default::default()
+    };
+    filter.core.id = Some(Cow::Borrowed("shadow"));
+
+    let settings = WriteSettings {
+        precision: 1,
+        ..Default::default()
+    };
+    assert_eq!(
+        Element::Filter(filter).write_to_string(&settings),
+        concat!(
+            r#"<filter filterUnits="userSpaceOnUse" id="shadow">"#,
+            r#"<feGaussianBlur in="SourceAlpha" stdDeviation="2.5" result="blur"/>"#,
+            r#"<feOffset dx="1.0" dy="-1.0"/>"#,
+            "</filter>"
+        )
+    );
+}
@@ -0,0 +1,473 @@
+//! Golden tests comparing written output of elements and attributes with
+//! exact expected strings.
+#![cfg(all(feature = "html", feature = "path", feature = "write"))]
+
+use std::{borrow::Cow, io::Write, rc::Rc};
+
+use structuredvg::{
+    common::{
+        ConditionalProcessing, CoreAttributes, DataAttribute, DelimitedValues, LanguageTag,
+        XmlSpace,
+    },
+    container::{Element, ElementGroup},
+    io::{
+        write_element_attributes, AttributeBundle, Dialect, Writable, WriteEscaped, WriteSettings,
+    },
+    math::{Length, PositiveNumber, Unit},
+    path::ElementPath,
+    shape::ElementRect,
+    svg::ElementSvg,
+};
+
+fn render<T: Writable>(t: &T, s: &WriteSettings) -> String {
+    t.write_to_string(s)
+}
+
+fn render_attributes<B: AttributeBundle>(bundle: &B, s: &WriteSettings) -> String {
+    let mut buffer = Vec::new();
+    bundle.write_attributes(&mut buffer, s).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+fn pretty() -> WriteSettings {
+    WriteSettings {
+        precision: 1,
+        indent: Some(2),
+        ..Default::default()
+    }
+}
+
+fn path(d: &str) -> ElementPath<'static> {
+    ElementPath {
+        conditional_processing: Default::default(),
+        core: Default::default(),
+        graphical_event: Default::default(),
+        references: Default::default(),
+        painting: Default::default(),
+        d: Some(d.parse().unwrap()),
+        path_length: None,
+    }
+}
+
+#[test]
+fn path_element() {
+    let mut element = path("M0 0 L10 5 z");
+    element.core.id = Some(Cow::Borrowed("outline"));
+    element.path_length = PositiveNumber::new(20.0);
+
+    assert_eq!(
+        render(&element, &WriteSettings::default()),
+        r#"<path d="M0.0000 0.0000L10.0000 5.0000z" pathLength="20.0000" id="outline"/>"#
+    );
+    assert_eq!(
+        render(&element, &pretty()),
+        r#"<path d="M0.0 0.0L10.0 5.0z" pathLength="20.0" id="outline"/>"#
+    );
+}
+
+#[test]
+fn path_element_without_attributes() {
+    let mut element = path("M0 0");
+    element.d = None;
+
+    assert_eq!(render(&element, &WriteSettings::default()), "<path/>");
+}
+
+#[test]
+fn core_attributes() {
+    let mut core = CoreAttributes {
+        id: Some(Cow::Borrowed("a&b")),
+        tabindex: Some(2),
+        xml_lang: Some(LanguageTag::new("en-US").unwrap()),
+        class: Some(DelimitedValues::with_values(["x".to_string(), "y".to_string()])),
+        ..Default::default()
+    };
+    core.data.push(DataAttribute::new("note", "\"quoted\""));
+    core.data.push(DataAttribute::new("empty", ""));
+
+    let expected = concat!(
+        r#"id="a&amp;b" tabindex="2" xml:lang="en-US" class="x y" "#,
+        r#"data-note="&quot;quoted&quot;" data-empty="""#
+    );
+    assert_eq!(render_attributes(&core, &WriteSettings::default()), expected);
+    assert_eq!(render_attributes(&core, &pretty()), expected);
+    assert_eq!(
+        render_attributes(&CoreAttributes::default(), &WriteSettings::default()),
+        ""
+    );
+}
+
+#[test]
+fn default_values_omitted() {
+    let mut core = CoreAttributes {
+        xml_space: XmlSpace::Default,
+        ..Default::default()
+    };
+    assert_eq!(render_attributes(&core, &WriteSettings::default()), "");
+
+    core.xml_space = XmlSpace::Preserve;
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        r#"xml:space="preserve""#
+    );
+}
+
+#[test]
+fn integer_attributes() {
+    let mut core = CoreAttributes {
+        tabindex: Some(-1),
+        ..Default::default()
+    };
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        r#"tabindex="-1""#
+    );
+
+    core.tabindex = Some(isize::MAX);
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        format!(r#"tabindex="{}""#, isize::MAX)
+    );
+    assert_eq!(render(&42u32, &WriteSettings::default()), "42");
+}
+
+#[test]
+fn conditional_processing() {
+    let mut languages = DelimitedValues::new();
+    languages.push(LanguageTag::new("en").unwrap());
+    languages.push(LanguageTag::new("de").unwrap());
+    let conditions = ConditionalProcessing {
+        required_extensions: Some(DelimitedValues::with_values([
+            "http://example.org/ext".to_string(),
+        ])),
+        system_language: Some(languages),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        render_attributes(&conditions, &WriteSettings::default()),
+        r#"requiredExtensions="http://example.org/ext" systemLanguage="en,de""#
+    );
+}
+
+#[test]
+fn delimited_values() {
+    let mut values: DelimitedValues<';'> = DelimitedValues::new();
+    assert_eq!(render(&values, &WriteSettings::default()), "");
+
+    values.push("0".to_string());
+    values.push("0.5".to_string());
+    values.push("a<b".to_string());
+    assert_eq!(render(&values, &WriteSettings::default()), "0;0.5;a&lt;b");
+    assert_eq!(render(&values, &pretty()), "0;0.5;a&lt;b");
+}
+
+#[test]
+fn delimited_numeric_values() {
+    let numbers: DelimitedValues<' ', PositiveNumber> = DelimitedValues::with_values(
+        [0.5, 2.0].map(|it| PositiveNumber::new(it).unwrap()),
+    );
+    assert_eq!(render(&numbers, &WriteSettings::default()), "0.5000 2.0000");
+
+    let lengths: DelimitedValues<',', Length> =
+        DelimitedValues::with_values([Length::from(1.5), Length::new(2.0, Unit::Mm)]);
+    assert_eq!(render(&lengths, &WriteSettings::default()), "1.5,2mm");
+}
+
+#[test]
+fn nested_children() {
+    let group = ElementGroup {
+        children: vec![
+            Element::Path(path("M0 0")),
+            Element::Group(ElementGroup {
+                children: vec![Element::Path(path("M1 1"))],
+                ..Default::default()
+            }),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        render(&group, &WriteSettings::default()),
+        r#"<g><path d="M0.0000 0.0000"/><g><path d="M1.0000 1.0000"/></g></g>"#
+    );
+    assert_eq!(
+        render(&group, &pretty()),
+        concat!(
+            "<g>\n",
+            "  <path d=\"M0.0 0.0\"/>\n",
+            "  <g>\n",
+            "    <path d=\"M1.0 1.0\"/>\n",
+            "  </g>\n",
+            "</g>"
+        )
+    );
+}
+
+#[test]
+fn empty_elements() {
+    let group = ElementGroup::default();
+    let element = path("M0 0");
+    let end_tags = WriteSettings {
+        self_close_empty: false,
+        ..Default::default()
+    };
+
+    assert_eq!(render(&group, &WriteSettings::default()), "<g/>");
+    assert_eq!(render(&group, &end_tags), "<g></g>");
+    assert_eq!(
+        render(&element, &end_tags),
+        r#"<path d="M0.0000 0.0000"></path>"#
+    );
+}
+
+#[test]
+fn html_dialect() {
+    let html = WriteSettings::builder().dialect(Dialect::Html).build();
+    let mut rect = ElementRect::default();
+
+    assert_eq!(render(&rect, &WriteSettings::default()), "<rect/>");
+    assert_eq!(render(&rect, &html), "<rect></rect>");
+
+    rect.core.xml_lang = Some(LanguageTag::new("en").unwrap());
+    rect.core.xml_space = XmlSpace::Preserve;
+    assert_eq!(
+        render(&rect, &WriteSettings::default()),
+        r#"<rect xml:lang="en" xml:space="preserve"/>"#
+    );
+    assert_eq!(
+        render(&rect, &html),
+        r#"<rect lang="en" xml:space="preserve"></rect>"#
+    );
+}
+
+#[test]
+fn escaped_string_value() {
+    let core = CoreAttributes {
+        id: Some(Cow::Owned(r#"say "hi" <b>"#.to_string())),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        r#"id="say &quot;hi&quot; &lt;b>""#
+    );
+
+    let mut buffer = Vec::new();
+    WriteEscaped::new(&mut buffer).write_all(b"a & b").unwrap();
+    assert_eq!(buffer, b"a &amp; b");
+}
+
+#[test]
+fn escaped_language() {
+    let core = CoreAttributes {
+        xml_lang: Some(LanguageTag::new("en\" onload=\"x").unwrap()),
+        ..Default::default()
+    };
+    let html = WriteSettings::builder().dialect(Dialect::Html).build();
+    let sorted = WriteSettings::builder().sort_attributes(true).build();
+
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        r#"xml:lang="en&quot; onload=&quot;x""#
+    );
+    assert_eq!(
+        render_attributes(&core, &html),
+        r#"lang="en&quot; onload=&quot;x""#
+    );
+
+    let rect = ElementRect {
+        core: Box::new(core),
+        ..Default::default()
+    };
+    assert_eq!(
+        render(&rect, &sorted),
+        r#"<rect xml:lang="en&quot; onload=&quot;x"/>"#
+    );
+}
+
+#[test]
+fn single_quoted_attributes() {
+    let mut core = CoreAttributes {
+        id: Some(Cow::Borrowed("it's \"here\"")),
+        tabindex: Some(1),
+        ..Default::default()
+    };
+    core.data.push(DataAttribute::new("note", "don't"));
+    let settings = WriteSettings::builder().attribute_quote('\'').build();
+
+    assert_eq!(
+        render_attributes(&core, &settings),
+        r#"id='it&apos;s "here"' tabindex='1' data-note='don&apos;t'"#
+    );
+    assert_eq!(
+        render_attributes(&core, &WriteSettings::default()),
+        r#"id="it's &quot;here&quot;" tabindex="1" data-note="don't""#
+    );
+
+    let sorted = WriteSettings::builder()
+        .attribute_quote('\'')
+        .sort_attributes(true)
+        .build();
+    let group = ElementGroup {
+        core: Box::new(core),
+        ..Default::default()
+    };
+    assert_eq!(
+        render(&group, &sorted),
+        r#"<g data-note='don&apos;t' id='it&apos;s "here"' tabindex='1'/>"#
+    );
+}
+
+#[test]
+fn nested_indentation() {
+    let group = ElementGroup {
+        children: vec![Element::Group(ElementGroup {
+            children: vec![Element::Group(ElementGroup {
+                children: vec![Element::Path(path("M0 0"))],
+                ..Default::default()
+            })],
+            ..Default::default()
+        })],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        render(&group, &pretty()),
+        concat!(
+            "<g>\n",
+            "  <g>\n",
+            "    <g>\n",
+            "      <path d=\"M0.0 0.0\"/>\n",
+            "    </g>\n",
+            "  </g>\n",
+            "</g>"
+        )
+    );
+
+    let mut buffer = Vec::new();
+    let indenter = pretty().indenter();
+    indenter.write_newline(&mut buffer).unwrap();
+    indenter.write_indent(&mut buffer, 2).unwrap();
+    assert_eq!(buffer, b"\n    ");
+
+    buffer.clear();
+    let compact = WriteSettings::default().indenter();
+    compact.write_newline(&mut buffer).unwrap();
+    compact.write_indent(&mut buffer, 2).unwrap();
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn sorted_attributes() {
+    let mut element = path("M0 0 L10 5");
+    element.core.id = Some(Cow::Borrowed("outline"));
+    element.core.xml_lang = Some(LanguageTag::new("en").unwrap());
+    element.core.data.push(DataAttribute::new("b", "2"));
+    element.core.data.push(DataAttribute::new("a", "1"));
+    element.path_length = PositiveNumber::new(20.0);
+    let sorted = WriteSettings::builder()
+        .precision(0)
+        .sort_attributes(true)
+        .build();
+
+    let first = render(&element, &sorted);
+    assert_eq!(
+        first,
+        concat!(
+            r#"<path d="M0 0L10 5" data-a="1" data-b="2" id="outline" pathLength="20" "#,
+            r#"xml:lang="en"/>"#
+        )
+    );
+    assert_eq!(render(&element, &sorted).as_bytes(), first.as_bytes());
+
+    // attributes are sorted by their HTML names when writing HTML
+    let html = WriteSettings {
+        dialect: Dialect::Html,
+        ..sorted
+    };
+    assert_eq!(
+        render(&element, &html),
+        concat!(
+            r#"<path d="M0 0L10 5" data-a="1" data-b="2" id="outline" lang="en" "#,
+            r#"pathLength="20"></path>"#
+        )
+    );
+}
+
+/// Element sharing its core attributes with other elements.
+struct SharedCore {
+    tag: &'static str,
+    core: Rc<CoreAttributes<'static>>,
+}
+
+impl Writable for SharedCore {
+    fn write_to<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        settings: &WriteSettings,
+    ) -> std::io::Result<()> {
+        write!(writer, "<{}", self.tag)?;
+        write_element_attributes(&self.core, writer, settings)?;
+        writer.write_all(b"/>")
+    }
+}
+
+#[test]
+fn shared_attribute_bundle() {
+    let core = Rc::new(CoreAttributes {
+        id: Some(Cow::Borrowed("shared")),
+        tabindex: Some(1),
+        ..Default::default()
+    });
+    let rect = SharedCore {
+        tag: "rect",
+        core: Rc::clone(&core),
+    };
+    let circle = SharedCore {
+        tag: "circle",
+        core: Rc::clone(&core),
+    };
+    assert_eq!(Rc::strong_count(&core), 3);
+
+    let sorted = WriteSettings {
+        sort_attributes: true,
+        ..Default::default()
+    };
+    for settings in [WriteSettings::default(), sorted] {
+        assert_eq!(
+            render(&rect, &settings),
+            r#"<rect id="shared" tabindex="1"/>"#
+        );
+        assert_eq!(
+            render(&circle, &settings),
+            r#"<circle id="shared" tabindex="1"/>"#
+        );
+    }
+}
+
+#[test]
+fn xml_prolog() {
+    let svg = ElementSvg::default();
+    let settings = WriteSettings::builder().xml_declaration(true).build();
+    let output = render(&svg, &settings);
+    assert!(output.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<svg "));
+    assert_eq!(output.matches("<?xml").count(), 1);
+
+    let settings = WriteSettings::builder()
+        .xml_declaration(true)
+        .standalone(true)
+        .doctype(r#"svg PUBLIC "-//W3C//DTD SVG 1.1//EN""#)
+        .build();
+    assert_eq!(
+        render(&svg, &settings),
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            "\n",
+            r#"<!DOCTYPE svg PUBLIC "-//W3C//DTD SVG 1.1//EN">"#,
+            "\n",
+            r#"<svg xmlns="http://www.w3.org/2000/svg"/>"#
+        )
+    );
+    assert!(render(&svg, &WriteSettings::default()).starts_with("<svg "));
+}
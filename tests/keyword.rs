@@ -0,0 +1,87 @@
+use structuredvg::{
+    common::XmlBool,
+    error::InvalidKeyword,
+    style::{FillRule, Inheritable, LineCap, LineJoin},
+    text::{DominantBaseline, TextAnchor},
+};
+
+#[test]
+fn keywords_round_trip() {
+    for cap in [LineCap::Butt, LineCap::Round, LineCap::Square] {
+        assert_eq!(cap.to_string().parse::<LineCap>(), Ok(cap));
+    }
+    assert_eq!("miter-clip".parse::<LineJoin>(), Ok(LineJoin::MiterClip));
+    assert_eq!(FillRule::EvenOdd.as_str(), "evenodd");
+    assert_eq!(TextAnchor::default(), TextAnchor::Start);
+}
+
+#[test]
+fn unknown_keyword_rejected() {
+    assert_eq!(
+        "bogus".parse::<LineCap>(),
+        Err(InvalidKeyword("bogus".to_string()))
+    );
+    assert!("Round".parse::<LineJoin>().is_err());
+    assert!("".parse::<FillRule>().is_err());
+    assert!("square-ish".parse::<LineCap>().is_err());
+    assert!("mitre".parse::<LineJoin>().is_err());
+    assert!("evenOdd".parse::<FillRule>().is_err());
+    assert_eq!(
+        "inherit".parse::<FillRule>(),
+        Err(InvalidKeyword("inherit".to_string()))
+    );
+}
+
+#[test]
+fn text_keywords() {
+    assert_eq!("middle".parse::<TextAnchor>(), Ok(TextAnchor::Middle));
+    assert_eq!(
+        "text-before-edge".parse::<DominantBaseline>(),
+        Ok(DominantBaseline::TextBeforeEdge)
+    );
+    assert_eq!(DominantBaseline::default(), DominantBaseline::Auto);
+    assert!("center".parse::<TextAnchor>().is_err());
+
+    assert_eq!(
+        "inherit".parse::<Inheritable<TextAnchor>>(),
+        Ok(Inheritable::Inherit)
+    );
+    assert_eq!(
+        "end".parse::<Inheritable<TextAnchor>>(),
+        Ok(Inheritable::Value(TextAnchor::End))
+    );
+    assert_eq!(
+        "inherit".parse::<Inheritable<DominantBaseline>>(),
+        Ok(Inheritable::Inherit)
+    );
+    assert!("inherit".parse::<DominantBaseline>().is_err());
+}
+
+#[test]
+fn inheritable_round_trip() {
+    for value in [Inheritable::Inherit, Inheritable::Value(LineCap::Round)] {
+        let written = value.to_string();
+        assert_eq!(written.parse::<Inheritable<LineCap>>(), Ok(value));
+    }
+    assert_eq!(Inheritable::<LineCap>::Inherit.to_string(), "inherit");
+    assert_eq!(Inheritable::Value(LineCap::Square).to_string(), "square");
+    assert_eq!(
+        Inheritable::<LineCap>::default().value(),
+        Some(&LineCap::Butt)
+    );
+}
+
+#[test]
+fn bool_spellings() {
+    assert_eq!("true".parse(), Ok(XmlBool(true)));
+    assert_eq!("1".parse(), Ok(XmlBool(true)));
+    assert_eq!("false".parse(), Ok(XmlBool(false)));
+    assert_eq!("0".parse(), Ok(XmlBool(false)));
+
+    for invalid in ["True", "yes", "01", " true", ""] {
+        assert_eq!(
+            invalid.parse::<XmlBool>(),
+            Err(InvalidKeyword(invalid.to_string()))
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use structuredvg::common::LanguageTag;
+
+fn canonical(tag: &str) -> String {
+    LanguageTag::new(tag).unwrap().canonicalize().to_string()
+}
+
+#[test]
+fn canonical_case() {
+    assert_eq!(canonical("EN-us"), "en-US");
+    assert_eq!(canonical("zh-hant-tw"), "zh-Hant-TW");
+    assert_eq!(canonical("SR-LATN-rs"), "sr-Latn-RS");
+    assert_eq!(canonical("es-419"), "es-419");
+    assert_eq!(canonical("de-CH-1996"), "de-CH-1996");
+}
+
+#[test]
+fn extensions_are_lowercased() {
+    assert_eq!(canonical("en-US-u-CA-GREGORY"), "en-US-u-ca-gregory");
+    assert_eq!(canonical("en-x-US-Test"), "en-x-us-test");
+    assert_eq!(canonical("X-Whatever"), "x-whatever");
+}
@@ -0,0 +1,56 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::PathData,
+};
+
+const ICON: &str = "M100 100 L200 100 L200 200 L100 200 Z \
+    M120 120 C120 130 130 140 140 140 C150 140 160 150 160 160 \
+    Q160 170 170 170 Q180 170 180 180 L180 190";
+
+#[test]
+fn minified_path_is_shorter() {
+    let path: PathData = ICON.parse().unwrap();
+    let minified = path.minify();
+
+    let settings = WriteSettings::default();
+    assert_eq!(path.write_to_string(&settings).len(), 289);
+    assert_eq!(minified.write_to_string(&settings).len(), 196);
+}
+
+#[test]
+fn minified_path_preserves_geometry() {
+    let path: PathData = ICON.parse().unwrap();
+    let minified = path.minify();
+
+    assert!(path.iter_absolute().eq(minified.iter_absolute()));
+    assert_eq!(minified.validate(), Ok(()));
+}
+
+#[test]
+fn minify_picks_shortest_commands() {
+    let path: PathData = "M10 10 L20 10 L20 20 C20 30 30 30 30 20 C30 10 40 10 40 20"
+        .parse()
+        .unwrap();
+    let settings = WriteSettings {
+        precision: 0,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        path.minify_with_precision(0).write_to_string(&settings),
+        "M10 10H20V20c0 10 10 10 10 0S40 10 40 20"
+    );
+}
+
+#[test]
+fn minified_path_approx_eq_original() {
+    let path: PathData = "M0.123456 0 L10.5 0.000001 A10 10 0 0 1 20.5 0"
+        .parse()
+        .unwrap();
+    let minified = path.minify();
+
+    assert!(minified.approx_eq(&path, 0.0001));
+    assert!(!minified.approx_eq(&path, 0.0000001));
+}
@@ -0,0 +1,29 @@
+use structuredvg::common::NonStandardAttribute;
+
+#[test]
+fn rejects_invalid_names() {
+    assert!(NonStandardAttribute::try_new("a b", "value").is_err());
+    assert!(NonStandardAttribute::try_new("1a", "value").is_err());
+    assert!(NonStandardAttribute::try_new("", "value").is_err());
+
+    assert!(NonStandardAttribute::try_new("fill-opacity", "1").is_ok());
+    assert!(NonStandardAttribute::try_new("xlink:href", "#a").is_ok());
+    assert!(NonStandardAttribute::try_new("_émoji.2", "").is_ok());
+}
+
+#[cfg(feature = "write")]
+#[test]
+fn escapes_value() {
+    use structuredvg::io::{Attribute, WriteSettings};
+
+    let attribute = NonStandardAttribute::try_new("title", "<a & \"b\">").unwrap();
+    let mut buffer = Vec::new();
+    attribute
+        .write_attribute(&mut buffer, &WriteSettings::default())
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        r#"title="&lt;a &amp; &quot;b&quot;>""#
+    );
+}
@@ -0,0 +1,18 @@
+use structuredvg::style::Opacity;
+
+#[test]
+fn parse_opacity() {
+    assert_eq!("50%".parse(), Ok(Opacity::new(0.5)));
+    assert_eq!("0.25".parse::<Opacity>().unwrap().into_inner(), 0.25);
+    assert_eq!("150%".parse(), Ok(Opacity::OPAQUE));
+    assert!("half".parse::<Opacity>().is_err());
+    assert!("%".parse::<Opacity>().is_err());
+}
+
+#[test]
+fn clamp_opacity() {
+    assert_eq!(Opacity::new(1.5).into_inner(), 1.0);
+    assert_eq!(Opacity::new(-0.5), Opacity::TRANSPARENT);
+    assert_eq!(Opacity::new(f32::NAN), Opacity::TRANSPARENT);
+    assert_eq!("1.5".parse::<Opacity>().unwrap().into_inner(), 1.0);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "write")]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    math::{Length, Unit},
+    shape::ElementRect,
+    style::{FuncIRIOrNone, Inheritable, Paint},
+};
+
+#[test]
+fn paint_shorthands() {
+    let mut rect = ElementRect::default();
+    rect.fill(Paint::color("red"));
+    rect.stroke(Paint::reference("outline"));
+    rect.stroke_width(Length::new(2.0, Unit::Px));
+
+    let output = rect.write_to_string(&WriteSettings::default());
+    assert_eq!(
+        output,
+        r##"<rect fill="red" stroke="url(#outline)" stroke-width="2.0000px"/>"##
+    );
+}
+
+#[test]
+fn parse_paint() {
+    assert_eq!("none".parse(), Ok(Paint::None));
+    assert_eq!(" currentColor ".parse(), Ok(Paint::CurrentColor));
+    assert_eq!("#ff8000".parse(), Ok(Paint::color("#ff8000")));
+    assert_eq!(
+        "url('#gradient') blue".parse(),
+        Ok(Paint::Server {
+            iri: Cow::Borrowed("#gradient"),
+            fallback: Some(Cow::Borrowed("blue")),
+        })
+    );
+    assert!("url(#gradient".parse::<Paint>().is_err());
+    assert!("".parse::<Paint>().is_err());
+}
+
+#[test]
+fn func_iri_references() {
+    let settings = WriteSettings::default();
+    assert_eq!(
+        FuncIRIOrNone::reference("a").write_to_string(&settings),
+        "url(#a)"
+    );
+    assert_eq!("none".parse(), Ok(FuncIRIOrNone::None));
+    assert_eq!(
+        "url('#a')".parse(),
+        Ok(FuncIRIOrNone::Url(Cow::Borrowed("#a")))
+    );
+    assert!("#a".parse::<FuncIRIOrNone>().is_err());
+    assert!("url()".parse::<FuncIRIOrNone>().is_err());
+
+    let mut rect = ElementRect::default();
+    rect.references.clip_path = Some(Inheritable::Value(FuncIRIOrNone::reference("clip")));
+    rect.references.mask = Some(Inheritable::Value(FuncIRIOrNone::None));
+    rect.references.filter = Some(Inheritable::Inherit);
+    assert_eq!(
+        rect.write_to_string(&settings),
+        r##"<rect clip-path="url(#clip)" mask="none" filter="inherit"/>"##
+    );
+}
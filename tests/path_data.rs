@@ -0,0 +1,262 @@
+#![cfg(feature = "path")]
+
+use structuredvg::{
+    error::PathError,
+    math::{Point, Vector},
+    path::{Command, PathData, PathSegment},
+    style::FillRule,
+};
+
+#[test]
+fn path_data_as_slice() {
+    let segments = vec![
+        PathSegment::move_to(0.0, 0.0),
+        PathSegment::line(10.0, 0.0),
+        PathSegment::line(10.0, 10.0),
+        PathSegment::close(),
+    ];
+    let path = PathData::from(segments.clone());
+
+    assert_eq!(path.len(), 4);
+    assert_eq!(path[0], segments[0]);
+    assert_eq!(&path[0..2], &segments[0..2]);
+    assert_eq!(path.as_ref(), segments.as_slice());
+    assert_eq!((&path).into_iter().count(), 4);
+    assert_eq!(path.into_iter().collect::<Vec<_>>(), segments);
+}
+
+#[test]
+fn command_statistics() {
+    let path: PathData = "M0 0 C1 1 2 2 3 3 S4 4 5 5 l1 1 h2 V3 A1 1 0 0 1 6 6 z M9 9 c1 1 2 2 3 3"
+        .parse()
+        .unwrap();
+
+    let counts = path.command_counts();
+    assert_eq!(counts[Command::Move as usize], 2);
+    assert_eq!(counts[Command::Cubic as usize], 2);
+    assert_eq!(counts[Command::CubicSmooth as usize], 1);
+    assert_eq!(counts[Command::Line as usize], 1);
+    assert_eq!(counts[Command::Horizontal as usize], 1);
+    assert_eq!(counts[Command::Vertical as usize], 1);
+    assert_eq!(counts[Command::Elliptical as usize], 1);
+    assert_eq!(counts[Command::Close as usize], 1);
+    assert_eq!(counts.iter().sum::<usize>(), path.len());
+
+    assert_eq!(path.coordinate_count(), 2 * 2 + 2 * 6 + 4 + 2 + 1 + 1 + 7);
+}
+
+#[test]
+fn content_hash_ignores_differences_below_precision() {
+    let a: PathData = "M0 0 L10.001 5 H20".parse().unwrap();
+    let b: PathData = "M0 0 l10.0014 5 h9.9986".parse().unwrap();
+    let c: PathData = "M0 0 L10.01 5 H20".parse().unwrap();
+
+    assert_eq!(a.content_hash(2), b.content_hash(2));
+    assert_ne!(a.content_hash(2), c.content_hash(2));
+    assert_ne!(a.content_hash(4), b.content_hash(4));
+}
+
+#[test]
+fn contains_point_in_square() {
+    let path: PathData = "M0 0 H10 V10 H0 Z".parse().unwrap();
+
+    assert!(path.contains_point(Point::new(5.0, 5.0), FillRule::NonZero, 0.1));
+    assert!(!path.contains_point(Point::new(15.0, 5.0), FillRule::NonZero, 0.1));
+    assert!(!PathData::with_capacity(0).contains_point(
+        Point::new(0.0, 0.0),
+        FillRule::NonZero,
+        0.1
+    ));
+}
+
+#[test]
+fn contains_point_fill_rules() {
+    // both subpaths wind in the same direction, the inner one is left open
+    let donut: PathData = "M0 0 H10 V10 H0 Z M3 3 H7 V7 H3".parse().unwrap();
+    let hole = Point::new(5.0, 5.0);
+    let ring = Point::new(1.0, 5.0);
+
+    assert!(!donut.contains_point(hole, FillRule::EvenOdd, 0.1));
+    assert!(donut.contains_point(ring, FillRule::EvenOdd, 0.1));
+    assert!(donut.contains_point(hole, FillRule::NonZero, 0.1));
+}
+
+#[test]
+fn contains_point_in_curve() {
+    let circle: PathData = "M0 5 A5 5 0 0 1 10 5 A5 5 0 0 1 0 5 Z".parse().unwrap();
+
+    assert!(circle.contains_point(Point::new(5.0, 9.5), FillRule::NonZero, 0.01));
+    assert!(!circle.contains_point(Point::new(0.5, 0.5), FillRule::NonZero, 0.01));
+}
+
+#[test]
+fn dedup_moves() {
+    let dedup = |d: &str| d.parse::<PathData>().unwrap().dedup_moves();
+
+    assert_eq!(dedup("M0 0 M5 5 L10 10"), "M5 5 L10 10".parse().unwrap());
+    assert_eq!(dedup("M1 1 m2 2 m3 3 l1 1"), "M6 6 l1 1".parse().unwrap());
+    assert_eq!(
+        dedup("M0 0 L1 1 z m1 1 m2 2 l1 0"),
+        "M0 0 L1 1 z m3 3 l1 0".parse().unwrap()
+    );
+    assert_eq!(dedup("M0 0 m1 1 M4 4 m1 0 h1"), "M5 4 h1".parse().unwrap());
+    assert_eq!(dedup("M0 0 L1 1 M2 2 M3 3"), "M0 0 L1 1".parse().unwrap());
+}
+
+#[test]
+fn parse_accepts_legal_paths() {
+    for d in ["M0 0 S1 1 2 2", "M0 0 T2 2", "M0 0 Z"] {
+        assert!(d.parse::<PathData>().is_ok(), "{}", d);
+    }
+    assert_eq!(
+        "L1 1".parse::<PathData>(),
+        Err(PathError::MissingInitialMove)
+    );
+}
+
+#[test]
+fn lint_path_data() {
+    let lint = |d: &str| d.parse::<PathData>().unwrap().lint();
+
+    assert_eq!(lint("M0 0 S1 1 2 2"), Err(PathError::OrphanSmooth(1)));
+    assert_eq!(lint("M0 0 T2 2"), Err(PathError::OrphanSmooth(1)));
+    assert_eq!(lint("M0 0 Z"), Err(PathError::CloseBeforeDraw(1)));
+    assert_eq!(lint("M0 0 C1 1 2 2 3 3 S4 4 5 5 Z"), Ok(()));
+}
+
+#[test]
+fn reverse() {
+    let square: PathData = "M0 0 L10 0 L10 10 Z".parse().unwrap();
+    assert_eq!(square.reverse(), "M0 0 L10 10 L10 0 Z".parse().unwrap());
+
+    let closed: PathData = "M0 0 L10 0 L10 10 L0 0 Z".parse().unwrap();
+    assert_eq!(closed.reverse(), "M0 0 L10 10 L10 0 Z".parse().unwrap());
+
+    let open: PathData = "M0 0 L10 0 Q10 10 0 10".parse().unwrap();
+    assert_eq!(open.reverse(), "M0 10 Q10 10 10 0 L0 0".parse().unwrap());
+}
+
+#[test]
+fn reverse_twice() {
+    for d in [
+        "M0 0 L10 0 L10 10 Z",
+        "M0 0 L10 0 Q10 10 0 10",
+        "M0 0 C5 0 10 5 10 10 Z M20 20 L30 20",
+    ] {
+        let path: PathData = d.parse().unwrap();
+        assert_eq!(path.reverse().reverse(), path, "{}", d);
+    }
+}
+
+#[test]
+fn round_to_grid() {
+    let path: PathData = "M0.4 0 L10.3 9.8".parse().unwrap();
+    assert_eq!(path.round_coordinates(), "M0 0 L10 10".parse().unwrap());
+
+    let arc: PathData = "M0 0 A-4.6 -0.2 0 0 1 10.2 0".parse().unwrap();
+    assert_eq!(
+        arc.round_to_grid(2.0),
+        "M0 0 A4 2 0 0 1 10 0".parse().unwrap()
+    );
+}
+
+#[test]
+fn point_at_distance() {
+    let line: PathData = "M0 0 L100 0".parse().unwrap();
+
+    assert_eq!(line.length(), 100.0);
+    assert_eq!(
+        line.point_at(50.0),
+        Some((Point::new(50.0, 0.0), Vector::new(1.0, 0.0)))
+    );
+    assert_eq!(line.point_at(0.0).map(|it| it.0), Some(Point::ORIGIN));
+    assert_eq!(line.point_at(100.5), None);
+    assert_eq!(line.point_at(-1.0), None);
+}
+
+#[test]
+fn split_subpaths() {
+    let path: PathData = "M0 0 L1 1 M2 2 L3 3".parse().unwrap();
+
+    let subpaths = path.subpaths();
+    assert_eq!(subpaths.len(), 2);
+    assert_eq!(subpaths[0], "M0 0 L1 1".parse().unwrap());
+    assert_eq!(subpaths[1], "M2 2 L3 3".parse().unwrap());
+    assert!(!path.is_closed());
+
+    let relative: PathData = "M0 0 L1 1 Z m2 2 l1 1 z".parse().unwrap();
+    assert_eq!(relative.subpaths()[1], "m2 2 l1 1 z".parse().unwrap());
+    assert!(relative.is_closed());
+}
+
+#[test]
+#[cfg(feature = "write")]
+fn append_paths() {
+    use structuredvg::io::{Writable, WriteSettings};
+
+    let mut path: PathData = "M0 0 L1 1".parse().unwrap();
+    path.append(&"M2 2 L3 3".parse().unwrap());
+    path.extend([PathSegment::move_to(4.0, 4.0)]);
+    path.push(PathSegment::line(5.0, 5.0));
+
+    assert_eq!(path.subpaths().len(), 3);
+    assert_eq!(
+        path.write_to_string(&WriteSettings::default().with_precision(0)),
+        "M0 0L1 1M2 2L3 3M4 4L5 5"
+    );
+}
+
+#[test]
+fn translate_and_scale() {
+    let line: PathData = "M0 0 L1 1".parse().unwrap();
+    assert_eq!(line.translate(2.0, 3.0), "M2 3 L3 4".parse().unwrap());
+    assert_eq!(line.scale(2.0, 2.0), "M0 0 L2 2".parse().unwrap());
+
+    let path: PathData = "M1 2 l3 4 h2 c1 1 2 2 3 3 s1 0 2 1 a2 2 0 0 1 4 0 z"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        path.translate(5.0, -2.5).translate(-5.0, 2.5),
+        path.to_absolute()
+    );
+    assert_eq!(path.scale(2.0, 2.0).scale(0.5, 0.5), path.to_absolute());
+    assert!(path.scale(4.0, 4.0).approx_eq(
+        &path
+            .translate(1.0, 1.0)
+            .scale(4.0, 4.0)
+            .translate(-4.0, -4.0),
+        1e-4
+    ));
+}
+
+#[test]
+fn iterate_absolute() {
+    let path: PathData = "m1 2 l3 4 c1 1 2 2 3 3 q1 0 2 1 a2 2 0 0 1 4 0 z m1 1 l2 2"
+        .parse()
+        .unwrap();
+
+    let iterated: Vec<_> = path
+        .iter_absolute()
+        .map(|(command, args)| (command, args.to_vec()))
+        .collect();
+    let absolute: Vec<_> = path
+        .to_absolute()
+        .iter()
+        .map(|it| (it.data.command(), it.data.args().to_vec()))
+        .collect();
+    assert_eq!(iterated, absolute);
+
+    let smooth: PathData = "M0 0 C1 0 2 1 3 3 s2 2 3 0 h1".parse().unwrap();
+    let explicit: Vec<_> = smooth
+        .iter_absolute()
+        .skip(2)
+        .map(|(command, args)| (command, args.to_vec()))
+        .collect();
+    assert_eq!(
+        explicit,
+        [
+            (Command::Cubic, vec![4., 5., 5., 5., 6., 3.]),
+            (Command::Line, vec![7., 3.]),
+        ]
+    );
+}
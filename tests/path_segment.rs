@@ -0,0 +1,101 @@
+#![cfg(feature = "path")]
+
+use structuredvg::path::{CommandData, PathData, PathSegment};
+
+#[test]
+fn constructors() {
+    assert_eq!(
+        PathSegment::move_to(1., 2.),
+        PathSegment {
+            relative: false,
+            data: CommandData::Move([1., 2.]),
+        }
+    );
+    assert_eq!(
+        PathSegment::rel_line(1., 2.),
+        PathSegment::new(true, CommandData::Line([1., 2.]))
+    );
+    assert_eq!(
+        PathSegment::horizontal(3.).data,
+        CommandData::Horizontal([3.])
+    );
+    assert_eq!(
+        PathSegment::rel_cubic(1., 2., 3., 4., 5., 6.).data,
+        CommandData::Cubic([1., 2., 3., 4., 5., 6.])
+    );
+    assert_eq!(
+        PathSegment::smooth_quadratic(1., 2.).data,
+        CommandData::QuadraticSmooth([1., 2.])
+    );
+    assert_eq!(
+        PathSegment::arc(5., 5., 0., true, false, 10., 0.).data,
+        CommandData::Elliptical([5., 5., 0., 1., 0., 10., 0.])
+    );
+    assert_eq!(
+        PathSegment::from(CommandData::Close([])),
+        PathSegment::close()
+    );
+}
+
+#[test]
+fn build_path() {
+    let built = PathData {
+        segments: vec![
+            PathSegment::move_to(0., 0.),
+            PathSegment::rel_horizontal(10.),
+            PathSegment::vertical(10.),
+            PathSegment::close(),
+        ],
+    };
+
+    assert_eq!(built, "M0 0 h10 V10 Z".parse().unwrap());
+}
+
+#[test]
+fn arc_flags() {
+    for (large_arc, sweep) in [(false, false), (false, true), (true, false), (true, true)] {
+        let data = CommandData::arc(5., 5., 0., large_arc, sweep, 10., 0.);
+        assert_eq!(data.large_arc_flag(), Some(large_arc));
+        assert_eq!(data.sweep_flag(), Some(sweep));
+    }
+    assert_eq!(
+        CommandData::arc(5., 5., 30., true, false, 10., 0.),
+        CommandData::Elliptical([5., 5., 30., 1., 0., 10., 0.])
+    );
+    assert_eq!(CommandData::Line([1., 2.]).large_arc_flag(), None);
+    assert_eq!(CommandData::Close([]).sweep_flag(), None);
+}
+
+#[test]
+fn hash_equal_paths() {
+    use std::collections::HashSet;
+
+    let a: PathData = "M0 0 L10 0 C1 2 3 4 5 6 Z".parse().unwrap();
+    let b: PathData = "M 0,0 L 10,0 C 1,2 3,4 5,6 Z".parse().unwrap();
+    let other: PathData = "M0 0 L10 1".parse().unwrap();
+
+    let paths: HashSet<PathData> = [a.clone(), b].into_iter().collect();
+    assert_eq!(paths.len(), 1);
+    assert!(paths.contains(&a));
+    assert!(!paths.contains(&other));
+}
+
+#[test]
+fn signed_zero_coordinates() {
+    assert_eq!(
+        CommandData::Line([-0.0, 1.0]),
+        CommandData::Line([0.0, 1.0])
+    );
+    assert_eq!(
+        PathSegment::move_to(0.0, -0.0),
+        PathSegment::move_to(-0.0, 0.0)
+    );
+    assert_eq!(
+        "M-0 0 L1 -0".parse::<PathData>().unwrap(),
+        "M0 0 L1 0".parse::<PathData>().unwrap()
+    );
+    assert_ne!(
+        CommandData::Line([0.0, 1.0]),
+        CommandData::Line([0.0, -1.0])
+    );
+}
@@ -0,0 +1,38 @@
+use structuredvg::shape::PointList;
+
+#[test]
+fn build_and_iterate() {
+    let mut points = PointList::from_slice(&[(0.0, 0.0), (10.0, 0.0)]);
+    points.push(10.0, 10.0);
+
+    assert_eq!(points.len(), 3);
+    assert_eq!(points.get(2), Some((10.0, 10.0)));
+    assert_eq!(points.get(3), None);
+    assert_eq!(
+        points.iter().collect::<Vec<_>>(),
+        [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]
+    );
+    assert_eq!(points, "0,0 10,0 10,10".parse().unwrap());
+}
+
+#[test]
+fn close_without_duplicating() {
+    let mut points = PointList::from_slice(&[(0.0, 0.0), (10.0, 0.0), (10.0, 10.0)]);
+    assert!(!points.is_closed());
+
+    points.close();
+    assert!(points.is_closed());
+    assert_eq!(points.len(), 4);
+
+    points.close();
+    assert_eq!(points.len(), 4);
+
+    let mut single = PointList::from_slice(&[(1.0, 1.0)]);
+    single.close();
+    assert_eq!(single.len(), 1);
+}
+
+#[test]
+fn odd_coordinate_count() {
+    assert!("0,0 10".parse::<PointList>().is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use structuredvg::{
+    io::{NumberFormat, Writable, WriteSettings},
+    math::{Length, Unit},
+    path::PathData,
+};
+
+#[test]
+fn precision_override_leaves_settings_untouched() {
+    let path: PathData = "M0 0 L0.1234567 1".parse().unwrap();
+    let settings = WriteSettings::default();
+
+    assert_eq!(
+        path.write_to_string(&settings.with_precision(6)),
+        "M0.000000 0.000000L0.123457 1.000000"
+    );
+    assert_eq!(settings.precision, 4);
+    assert_eq!(
+        path.write_to_string(&settings),
+        "M0.0000 0.0000L0.1235 1.0000"
+    );
+}
+
+#[test]
+fn write_precise() {
+    let path: PathData = "M0 0 L0.1234567 1".parse().unwrap();
+
+    let mut buffer = Vec::new();
+    path.write_to_precise(&mut buffer, 1).unwrap();
+    assert_eq!(buffer, b"M0.0 0.0L0.1 1.0");
+}
+
+#[test]
+fn arc_flags_written_as_digits() {
+    let path: PathData = "M0 0 a25 25 0 1 0 50 0".parse().unwrap();
+    let settings = WriteSettings::default();
+
+    assert_eq!(
+        path.write_to_string(&settings.with_precision(0)),
+        "M0 0a25 25 0 1 0 50 0"
+    );
+    assert_eq!(
+        path.write_to_string(&settings),
+        "M0.0000 0.0000a25.0000 25.0000 0.0000 1 0 50.0000 0.0000"
+    );
+}
+
+#[test]
+fn leading_zero() {
+    let path: PathData = "M0.5 -0.25 L1 10".parse().unwrap();
+    let settings = WriteSettings::default().with_precision(2);
+    let short = WriteSettings::builder()
+        .precision(2)
+        .number_format(NumberFormat {
+            leading_zero: false,
+        })
+        .build();
+
+    assert_eq!(path.write_to_string(&settings), "M0.50 -0.25L1.00 10.00");
+    assert_eq!(path.write_to_string(&short), "M.50 -.25L1.00 10.00");
+    assert_eq!(Length::new(0.5, Unit::Em).write_to_string(&short), ".50em");
+}
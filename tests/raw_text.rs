@@ -0,0 +1,53 @@
+#![cfg(feature = "write")]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    io::{Dialect, Writable, WriteSettings},
+    script::ElementScript,
+    style::ElementStyle,
+};
+
+const CONTENT: &str = "if (a < b) { x = y[z[0]]> 1; }";
+
+fn xml() -> WriteSettings {
+    WriteSettings::default()
+}
+
+fn html() -> WriteSettings {
+    WriteSettings::builder().dialect(Dialect::Html).build()
+}
+
+#[test]
+fn script_content() {
+    let script = ElementScript {
+        content: Cow::Borrowed(CONTENT),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        script.write_to_string(&xml()),
+        "<script><![CDATA[if (a < b) { x = y[z[0]]]]><![CDATA[> 1; }]]></script>"
+    );
+    assert_eq!(
+        script.write_to_string(&html()),
+        format!("<script>{}</script>", CONTENT)
+    );
+}
+
+#[test]
+fn style_content() {
+    let style = ElementStyle {
+        content: Cow::Borrowed("a < b ]]>"),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        style.write_to_string(&xml()),
+        "<style><![CDATA[a < b ]]]]><![CDATA[>]]></style>"
+    );
+    assert_eq!(style.write_to_string(&html()), "<style>a < b ]]></style>");
+
+    let empty = ElementStyle::default();
+    assert_eq!(empty.write_to_string(&xml()), "<style></style>");
+}
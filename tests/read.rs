@@ -0,0 +1,156 @@
+#![cfg(all(feature = "html", feature = "read-xml", feature = "write"))]
+
+use structuredvg::{
+    common::Units,
+    container::{Element, UnknownContent},
+    error::ReadError,
+    filter::FilterPrimitive,
+    io::{Writable, WriteSettings},
+    svg::read_document,
+};
+
+const DOCUMENT: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+<!-- icon -->
+<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 24 24" version="1.1">
+  <g id="shapes" clip-path="url(#clip)">
+    <path d="M0 0L10 5z" data-kind="outline"/>
+    <circle cx="12" cy="12" r="4"/>
+    <desc>Shapes</desc>
+  </g>
+  <style><![CDATA[path { fill: red; }]]></style>
+</svg>"##;
+
+#[test]
+fn read_elements_and_attributes() {
+    let svg = read_document(DOCUMENT.as_bytes()).unwrap();
+
+    assert_eq!(svg.view_box.map(|it| it.width), Some(24.));
+    assert_eq!(svg.core.other.len(), 1);
+    assert_eq!(svg.core.other[0].name, "version");
+    assert_eq!(svg.children.len(), 2);
+
+    let Element::Group(group) = &svg.children[0] else {
+        panic!("expected a group");
+    };
+    assert_eq!(group.core.id.as_deref(), Some("shapes"));
+    assert!(group.references.clip_path.is_some());
+    assert_eq!(group.children.len(), 3);
+
+    let Element::Path(path) = &group.children[0] else {
+        panic!("expected a path");
+    };
+    assert!(path.d.is_some());
+    assert_eq!(path.core.get_data("kind"), Some("outline"));
+
+    let Element::Unknown(desc) = &group.children[2] else {
+        panic!("expected an unknown element");
+    };
+    assert_eq!(desc.tag, "desc");
+
+    let Element::Style(style) = &svg.children[1] else {
+        panic!("expected a style element");
+    };
+    assert_eq!(style.content, "path { fill: red; }");
+}
+
+#[test]
+fn read_then_write() {
+    let svg = read_document(DOCUMENT.as_bytes()).unwrap();
+    let settings = WriteSettings {
+        precision: 0,
+        ..Default::default()
+    };
+
+    let written = svg.write_to_string(&settings);
+    assert_eq!(
+        read_document(written.as_bytes())
+            .unwrap()
+            .write_to_string(&settings),
+        written
+    );
+}
+
+#[test]
+fn preserve_unknown_elements() {
+    let document = r#"<svg><foo bar="1"><ns:baz/><g id="inner"></g></foo></svg>"#;
+    let svg = read_document(document.as_bytes()).unwrap();
+
+    let Element::Unknown(foo) = &svg.children[0] else {
+        panic!("expected an unknown element");
+    };
+    assert_eq!(
+        foo.write_to_string(&WriteSettings::default()),
+        r#"<foo bar="1"><ns:baz/><g id="inner"/></foo>"#
+    );
+}
+
+#[test]
+fn preserve_unknown_text() {
+    let document = r#"<svg><foo bar="1">hi<g/> &amp; <![CDATA[<raw>]]></foo></svg>"#;
+    let svg = read_document(document.as_bytes()).unwrap();
+
+    let Element::Unknown(foo) = &svg.children[0] else {
+        panic!("expected an unknown element");
+    };
+    assert_eq!(foo.children.len(), 3);
+    assert!(matches!(&foo.children[0], UnknownContent::Text(text) if text == "hi"));
+    assert!(matches!(&foo.children[2], UnknownContent::Text(text) if text == " & <raw>"));
+    assert_eq!(
+        foo.write_to_string(&WriteSettings::default()),
+        r#"<foo bar="1">hi<g/> &amp; &lt;raw&gt;</foo>"#
+    );
+
+    let simple = read_document(r#"<svg><foo bar="1">hi<g/></foo></svg>"#.as_bytes()).unwrap();
+    assert_eq!(
+        simple.children[0].write_to_string(&WriteSettings::default()),
+        r#"<foo bar="1">hi<g/></foo>"#
+    );
+}
+
+#[test]
+fn read_filter() {
+    let document = concat!(
+        r#"<svg><filter id="shadow" filterUnits="userSpaceOnUse">"#,
+        r#"<feGaussianBlur in="SourceAlpha" stdDeviation="2.5" result="blur"/>"#,
+        r#"<feFlood flood-color="black"/>"#,
+        r#"<feOffset dx="1" dy="-1"></feOffset>"#,
+        "</filter></svg>"
+    );
+    let svg = read_document(document.as_bytes()).unwrap();
+
+    let Element::Filter(filter) = &svg.children[0] else {
+        panic!("expected a filter");
+    };
+    assert_eq!(filter.core.id.as_deref(), Some("shadow"));
+    assert_eq!(filter.filter_units, Some(Units::UserSpaceOnUse));
+    assert_eq!(filter.children.len(), 2);
+
+    let FilterPrimitive::GaussianBlur(blur) = &filter.children[0] else {
+        panic!("expected a Gaussian blur");
+    };
+    assert_eq!(blur.input.as_deref(), Some("SourceAlpha"));
+    assert_eq!(blur.std_deviation.map(|it| *it), Some(2.5));
+    assert_eq!(blur.primitive.result.as_deref(), Some("blur"));
+
+    let FilterPrimitive::Offset(offset) = &filter.children[1] else {
+        panic!("expected an offset");
+    };
+    assert_eq!((offset.dx, offset.dy), (Some(1.0), Some(-1.0)));
+}
+
+#[test]
+fn read_errors() {
+    assert!(matches!(
+        read_document(r#"<svg><rect width="-1"/></svg>"#.as_bytes()),
+        Err(ReadError::Attribute(_))
+    ));
+    assert!(matches!(
+        read_document("<html></html>".as_bytes()),
+        Err(ReadError::UnexpectedRoot(name)) if name == "html"
+    ));
+    assert!(matches!(
+        read_document("".as_bytes()),
+        Err(ReadError::MissingRoot)
+    ));
+    assert!(read_document("<svg><g></svg>".as_bytes()).is_err());
+}
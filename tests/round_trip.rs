@@ -0,0 +1,94 @@
+//! Checks that writing parsed values is stable, i.e. that parsing written
+//! output and writing it again doesn't change it.
+#![cfg(feature = "write")]
+
+use std::{fmt::Debug, str::FromStr};
+
+use structuredvg::{
+    animation::{AnimationFill, ClockValue, RepeatCount, TransformType},
+    common::{PreserveAspectRatio, XmlBool, XmlSpace},
+    io::{Writable, WriteSettings},
+    marker::Orient,
+    math::{Length, NonNegativeLength, ViewBox},
+    paint::Units,
+    shape::PointList,
+    style::{
+        Color, DashArray, FillRule, FuncIRIOrNone, Inheritable, LineCap, LineJoin, Opacity, Paint,
+    },
+    text::{DominantBaseline, TextAnchor},
+};
+
+fn assert_round_trip<T>(input: &str)
+where
+    T: FromStr + Writable + PartialEq + Debug,
+    T::Err: Debug,
+{
+    let settings = WriteSettings::default();
+    let parsed: T = input.parse().unwrap();
+    let written = parsed.write_to_string(&settings);
+    let reparsed: T = written
+        .parse()
+        .unwrap_or_else(|err| panic!("unable to parse written '{written}': {err:?}"));
+
+    assert_eq!(
+        reparsed.write_to_string(&settings),
+        written,
+        "input: '{input}'"
+    );
+    assert_eq!(reparsed, written.parse().unwrap());
+}
+
+#[test]
+fn numeric_values() {
+    assert_round_trip::<Length>("10");
+    assert_round_trip::<Length>("-2.5mm");
+    assert_round_trip::<Length>("0.123456em");
+    assert_round_trip::<NonNegativeLength>("3in");
+    assert_round_trip::<Opacity>("0.25");
+    assert_round_trip::<ViewBox>("0 0 100.5 50");
+    assert_round_trip::<ViewBox>("-1,-1,2,2");
+    assert_round_trip::<DashArray>("5 2.5");
+    assert_round_trip::<DashArray>("none");
+    assert_round_trip::<PointList>("0,0 10,5 3.33333,1");
+    assert_round_trip::<ClockValue>("00:10.25");
+    assert_round_trip::<RepeatCount>("2.5");
+    assert_round_trip::<Orient>("45deg");
+}
+
+#[test]
+fn keywords() {
+    assert_round_trip::<TextAnchor>("middle");
+    assert_round_trip::<DominantBaseline>("hanging");
+    assert_round_trip::<XmlSpace>("preserve");
+    assert_round_trip::<XmlBool>("true");
+    assert_round_trip::<LineCap>("round");
+    assert_round_trip::<LineJoin>("bevel");
+    assert_round_trip::<FillRule>("evenodd");
+    assert_round_trip::<Inheritable<FillRule>>("inherit");
+    assert_round_trip::<PreserveAspectRatio>("defer xMinYMax slice");
+    assert_round_trip::<Units>("objectBoundingBox");
+    assert_round_trip::<Units>("strokeWidth");
+    assert_round_trip::<Orient>("auto-start-reverse");
+    assert_round_trip::<RepeatCount>("indefinite");
+    assert_round_trip::<AnimationFill>("freeze");
+    assert_round_trip::<TransformType>("skewX");
+}
+
+#[test]
+fn references() {
+    assert_round_trip::<FuncIRIOrNone>("url(#clip)");
+    assert_round_trip::<FuncIRIOrNone>("none");
+    assert_round_trip::<Paint>("url('#gradient') red");
+    assert_round_trip::<Paint>("currentColor");
+    assert_round_trip::<Color>("rgba(0, 0, 0, 0.5)");
+    assert_round_trip::<Color>("hsl(120, 50%, 50%)");
+}
+
+#[cfg(feature = "path")]
+#[test]
+fn path_data() {
+    use structuredvg::path::PathData;
+
+    assert_round_trip::<PathData>("M0 0 L10 5 H20 V0.123456 C1 2 3 4 5 6 S1 2 3 4 Z");
+    assert_round_trip::<PathData>("m1 1 q1 2 3 4 t5 6 a5 5 30 1 0 10 10 z");
+}
@@ -0,0 +1,72 @@
+#![cfg(all(feature = "html", feature = "serde", feature = "write"))]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    common::{
+        CoreAttributes, DataAttribute, DelimitedValues, LanguageTag, NonStandardAttribute, XmlSpace,
+    },
+    container::{Element, ElementGroup},
+    io::{AttributeBundle, Writable, WriteSettings},
+    shape::ElementCircle,
+    style::Paint,
+};
+
+fn render_attributes<B: AttributeBundle>(bundle: &B) -> String {
+    let mut buffer = Vec::new();
+    bundle
+        .write_attributes(&mut buffer, &WriteSettings::default())
+        .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn core_attributes_json_round_trip() {
+    let mut core = CoreAttributes {
+        id: Some(Cow::Borrowed("logo")),
+        tabindex: Some(-1),
+        xml_lang: Some(LanguageTag::new("en-US").unwrap()),
+        xml_space: XmlSpace::Preserve,
+        class: Some(DelimitedValues::with_values([
+            "a".to_string(),
+            "b".to_string(),
+        ])),
+        style: Some("fill: red; --accent: blue".parse().unwrap()),
+        ..Default::default()
+    };
+    core.data.push(DataAttribute::new("note", "\"quoted\""));
+    core.other
+        .push(NonStandardAttribute::try_new("inkscape:label", "Layer 1").unwrap());
+
+    let json = serde_json::to_string(&core).unwrap();
+    assert!(json.contains(r#""class":"a b""#));
+    assert!(json.contains(r#""xml_lang":"en-US""#));
+
+    let parsed: CoreAttributes = serde_json::from_str(&json).unwrap();
+    assert_eq!(render_attributes(&parsed), render_attributes(&core));
+    assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+}
+
+#[test]
+fn element_json_round_trip() {
+    let mut circle = ElementCircle {
+        cx: Some("50%".parse().unwrap()),
+        r: Some("4".parse().unwrap()),
+        ..Default::default()
+    };
+    circle.fill(Paint::reference("gradient"));
+    let group = Element::Group(ElementGroup {
+        children: vec![Element::Circle(circle)],
+        ..Default::default()
+    });
+
+    let json = serde_json::to_string(&group).unwrap();
+    let parsed: Element = serde_json::from_str(&json).unwrap();
+    let settings = WriteSettings::default();
+    assert_eq!(
+        parsed.write_to_string(&settings),
+        group.write_to_string(&settings)
+    );
+
+    assert!(serde_json::from_str::<Element>(r#"{"Circle":{"cx":"50%%"}}"#).is_err());
+}
@@ -0,0 +1,58 @@
+use structuredvg::{
+    common::LanguageTag,
+    error::InvalidNumber,
+    math::{Length, Number, PositiveNumber, Unit},
+    style::LineCap,
+};
+
+#[test]
+fn try_into_values() {
+    let length: Length = "12px".try_into().unwrap();
+    assert_eq!(length.unit, Some(Unit::Px));
+
+    let cap: LineCap = "round".try_into().unwrap();
+    assert_eq!(cap, LineCap::Round);
+
+    let tag: LanguageTag<'static> = String::from("en-US").as_str().try_into().unwrap();
+    assert_eq!(&*tag, "en-US");
+}
+
+#[test]
+fn try_into_invalid() {
+    assert!(Length::try_from("12 apples").is_err());
+    assert!(LineCap::try_from("pointy").is_err());
+}
+
+#[test]
+fn try_into_positive_number() {
+    assert_eq!(
+        PositiveNumber::try_from(Number::NAN),
+        Err(InvalidNumber::NaN)
+    );
+    assert_eq!(
+        PositiveNumber::try_from(-1.0f32),
+        Err(InvalidNumber::Negative)
+    );
+    assert_eq!(PositiveNumber::try_from(f64::NAN), Err(InvalidNumber::NaN));
+    assert_eq!(
+        PositiveNumber::try_from(-0.5f64),
+        Err(InvalidNumber::Negative)
+    );
+    assert_eq!(
+        PositiveNumber::try_from(Number::INFINITY),
+        Err(InvalidNumber::Infinite)
+    );
+
+    let number = PositiveNumber::try_from(2.5f64).unwrap();
+    assert_eq!(Number::from(number), 2.5);
+}
+
+#[cfg(feature = "path")]
+#[test]
+fn try_into_path() {
+    use structuredvg::path::PathData;
+
+    let path: PathData = "M0 0 L1 1".try_into().unwrap();
+    assert_eq!(path.segments.len(), 2);
+    assert!(PathData::try_from("M0 0 Q").is_err());
+}
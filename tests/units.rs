@@ -0,0 +1,29 @@
+use structuredvg::{common::Units, error::InvalidKeyword};
+
+#[test]
+fn parse_units() {
+    assert_eq!("userSpaceOnUse".parse(), Ok(Units::UserSpaceOnUse));
+    assert_eq!("objectBoundingBox".parse(), Ok(Units::ObjectBoundingBox));
+    assert_eq!("strokeWidth".parse(), Ok(Units::StrokeWidth));
+
+    for keyword in ["userspaceonuse", "ObjectBoundingBox", "stroke-width", ""] {
+        assert_eq!(
+            keyword.parse::<Units>(),
+            Err(InvalidKeyword(keyword.to_string()))
+        );
+    }
+}
+
+#[test]
+fn attribute_defaults() {
+    assert_eq!(
+        Units::default_for("clipPathUnits"),
+        Some(Units::UserSpaceOnUse)
+    );
+    assert_eq!(
+        Units::default_for("maskUnits"),
+        Some(Units::ObjectBoundingBox)
+    );
+    assert_eq!(Units::default_for("markerUnits"), Some(Units::StrokeWidth));
+    assert_eq!(Units::default_for("viewBox"), None);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "write")]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    common::NonStandardAttribute,
+    container::{Element, ElementGroup, ElementUnknown, UnknownContent},
+    io::{Dialect, Writable, WriteSettings},
+};
+
+fn foo() -> ElementUnknown<'static> {
+    ElementUnknown {
+        tag: "foo".to_string(),
+        attributes: vec![NonStandardAttribute {
+            name: Cow::Borrowed("bar"),
+            value: Cow::Borrowed("1"),
+        }],
+        children: Vec::new(),
+    }
+}
+
+#[test]
+fn write_unknown() {
+    let mut element = foo();
+    assert_eq!(
+        element.write_to_string(&WriteSettings::default()),
+        r#"<foo bar="1"/>"#
+    );
+
+    element
+        .children
+        .push(Element::Group(ElementGroup::default()).into());
+    assert_eq!(
+        element.write_to_string(&WriteSettings::default()),
+        r#"<foo bar="1"><g/></foo>"#
+    );
+}
+
+#[test]
+fn write_unknown_html() {
+    let settings = WriteSettings {
+        dialect: Dialect::Html,
+        ..Default::default()
+    };
+    let element = ElementUnknown {
+        tag: "foo".to_string(),
+        ..Default::default()
+    };
+    assert_eq!(element.write_to_string(&settings), "<foo></foo>");
+}
+
+#[test]
+fn write_unknown_text() {
+    let mut element = foo();
+    element.children = vec![
+        UnknownContent::Text(Cow::Borrowed("a < b && ]]>")),
+        Element::Group(ElementGroup::default()).into(),
+    ];
+    let expected = r#"<foo bar="1">a &lt; b &amp;&amp; ]]&gt;<g/></foo>"#;
+    assert_eq!(element.write_to_string(&WriteSettings::default()), expected);
+
+    // mixed content isn't indented
+    let pretty = WriteSettings::builder().indent(2).build();
+    assert_eq!(element.write_to_string(&pretty), expected);
+}
@@ -0,0 +1,18 @@
+#![cfg(feature = "path")]
+
+use structuredvg::{math::ViewBox, path::PathData};
+
+#[test]
+fn fit_unit_square_with_padding() {
+    let path: PathData = "M0 0 H1 V1 H0 Z".parse().unwrap();
+
+    assert_eq!(path.fit_view_box(1.), Some(ViewBox::new(-1., -1., 3., 3.)));
+    assert_eq!(path.fit_view_box(0.), Some(ViewBox::new(0., 0., 1., 1.)));
+    assert_eq!(path.fit_view_box(-1.), Some(ViewBox::new(1., 1., 0., 0.)));
+}
+
+#[test]
+fn fit_empty_path() {
+    let path: PathData = "".parse().unwrap();
+    assert_eq!(path.fit_view_box(1.), None);
+}
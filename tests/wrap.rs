@@ -0,0 +1,46 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::{PathData, PathSegment},
+    shape::PointList,
+};
+
+#[test]
+fn wrap_long_path() {
+    let mut path = PathData::from(vec![PathSegment::move_to(0.0, 0.0)]);
+    for i in 1..100 {
+        path.push(PathSegment::line(i as f32, (i * 7 % 13) as f32));
+    }
+    let settings = WriteSettings::builder()
+        .precision(1)
+        .max_line_width(40)
+        .build();
+
+    let output = path.write_to_string(&settings);
+    let lines: Vec<_> = output.lines().collect();
+    assert!(lines.len() > 1);
+    for line in &lines {
+        assert!(line.len() <= 40, "{:?} exceeds the limit", line);
+    }
+    assert_eq!(output.parse::<PathData>().unwrap(), path);
+
+    let unwrapped = path.write_to_string(&WriteSettings::default().with_precision(1));
+    assert_eq!(unwrapped.lines().count(), 1);
+}
+
+#[test]
+fn wrap_nested_points() {
+    let points = PointList::from_slice(&[(100.0, 200.0); 10]);
+    let settings = WriteSettings::builder()
+        .precision(0)
+        .indent(2)
+        .max_line_width(20)
+        .build();
+
+    let output = points.write_to_string(&settings);
+    assert_eq!(output.lines().count(), 5);
+    assert!(output.lines().skip(1).all(|it| it.starts_with("  1")));
+    assert!(output.lines().all(|it| it.len() <= 20));
+    assert_eq!(output.parse::<PointList>().unwrap(), points);
+}
@@ -0,0 +1,42 @@
+#![cfg(all(feature = "path", feature = "write"))]
+
+use std::borrow::Cow;
+
+use structuredvg::{
+    io::{Writable, WriteSettings},
+    path::PathData,
+};
+
+fn render(value: impl Writable) -> String {
+    value.write_to_string(&WriteSettings::default())
+}
+
+#[test]
+fn write_through_reference() {
+    let path: PathData = "M0 0 L10 5 z".parse().unwrap();
+
+    assert_eq!(render(&path), render(path));
+}
+
+#[test]
+fn write_through_cow() {
+    let path: PathData = "M0 0 L10 5 z".parse().unwrap();
+    let borrowed: Cow<PathData> = Cow::Borrowed(&path);
+    let owned: Cow<PathData> = Cow::Owned(path.clone());
+
+    assert_eq!(render(&borrowed), render(&path));
+    assert_eq!(render(owned), render(path));
+    assert_eq!(render(Cow::Borrowed("a&b")), "a&amp;b");
+}
+
+#[test]
+fn write_to_vec_matches_string() {
+    let path: PathData = "M0 0 L10 5 A5 5 0 0 1 20 0 z".parse().unwrap();
+    let settings = WriteSettings::default();
+
+    assert_eq!(
+        path.write_to_vec(&settings),
+        path.write_to_string(&settings).into_bytes()
+    );
+    assert_eq!(Cow::Borrowed("a&b").write_to_vec(&settings), b"a&amp;b");
+}